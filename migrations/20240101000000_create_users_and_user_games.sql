@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS users (
+    id BLOB PRIMARY KEY NOT NULL,
+    username TEXT NOT NULL,
+    email TEXT NOT NULL UNIQUE,
+    password TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS user_games (
+    user_id BLOB NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    game_id INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    added_at DATETIME NOT NULL,
+    is_favorite BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (user_id, game_id)
+);
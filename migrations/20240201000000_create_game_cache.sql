@@ -0,0 +1,9 @@
+-- Read-through cache of game metadata fetched from the external game provider.
+CREATE TABLE IF NOT EXISTS game_cache (
+    game_id INTEGER PRIMARY KEY NOT NULL,
+    payload TEXT NOT NULL,
+    cached_at INTEGER NOT NULL,
+    expires_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_game_cache_expires_at ON game_cache (expires_at);
@@ -0,0 +1,45 @@
+-- Local mirror of the external game catalog, filled by the catalog sync job.
+CREATE TABLE IF NOT EXISTS catalog_games (
+    game_id INTEGER PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    release_date TEXT,
+    rating REAL,
+    updated_at INTEGER NOT NULL,
+    payload TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_catalog_games_name ON catalog_games (name COLLATE NOCASE);
+CREATE INDEX IF NOT EXISTS idx_catalog_games_release_date ON catalog_games (release_date);
+CREATE INDEX IF NOT EXISTS idx_catalog_games_rating ON catalog_games (rating);
+
+CREATE TABLE IF NOT EXISTS catalog_game_platforms (
+    game_id INTEGER NOT NULL,
+    platform_id INTEGER NOT NULL,
+    PRIMARY KEY (game_id, platform_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_catalog_game_platforms_platform ON catalog_game_platforms (platform_id);
+
+CREATE TABLE IF NOT EXISTS catalog_game_genres (
+    game_id INTEGER NOT NULL,
+    genre TEXT NOT NULL,
+    PRIMARY KEY (game_id, genre)
+);
+
+CREATE INDEX IF NOT EXISTS idx_catalog_game_genres_genre ON catalog_game_genres (genre);
+
+-- Single row holding the sync cursor and the progress of the current or last run.
+CREATE TABLE IF NOT EXISTS catalog_sync_state (
+    id INTEGER PRIMARY KEY NOT NULL CHECK (id = 1),
+    status TEXT NOT NULL,
+    cursor_updated_at INTEGER NOT NULL,
+    cursor_game_id INTEGER NOT NULL,
+    synced_games INTEGER NOT NULL,
+    remaining_games INTEGER,
+    started_at DATETIME,
+    finished_at DATETIME,
+    last_error TEXT
+);
+
+INSERT OR IGNORE INTO catalog_sync_state (id, status, cursor_updated_at, cursor_game_id, synced_games)
+VALUES (1, 'IDLE', 0, 0, 0);
@@ -0,0 +1,2 @@
+-- WANT_TO_PLAY used to be stored without its underscores.
+UPDATE user_games SET status = 'WANT_TO_PLAY' WHERE status = 'WANTTOPLAY';
@@ -0,0 +1,4 @@
+-- Payloads cached before similar game ids, time-to-beat and sources were added still parse,
+-- with those fields empty, so they would be served as is until they expire. Drop them once.
+-- Payloads that no longer parse (e.g. the old image shape) are treated as misses anyway.
+DELETE FROM game_cache;
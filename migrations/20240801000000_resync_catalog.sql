@@ -0,0 +1,4 @@
+-- Catalog payloads synced before similar game ids and time-to-beat existed lack them, and the
+-- incremental sync would never revisit those games. Rewinding the cursor makes the next sync
+-- a full one.
+UPDATE catalog_sync_state SET cursor_updated_at = 0, cursor_game_id = 0 WHERE id = 1;
@@ -0,0 +1,4 @@
+-- Identifies the run that owns the sync and when it last made progress, so a second run
+-- is refused while the first is alive and can take over once it has stalled or crashed.
+ALTER TABLE catalog_sync_state ADD COLUMN run_id TEXT;
+ALTER TABLE catalog_sync_state ADD COLUMN heartbeat_at INTEGER;
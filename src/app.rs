@@ -0,0 +1,312 @@
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+use axum::Router;
+use reqwest::Client;
+use sqlx::SqlitePool;
+use tower_http::cors::{CorsLayer, Any};
+
+use crate::infrastructure::persistence::user_repository::SqliteUserRepository;
+use crate::infrastructure::persistence::library_repository::SqliteLibraryRepository;
+use crate::infrastructure::persistence::cached_game_provider::SqliteCachedGameProvider;
+use crate::infrastructure::persistence::catalog_game_provider::SqliteCatalogGameProvider;
+use crate::infrastructure::persistence::game_catalog_repository::SqliteGameCatalogRepository;
+use crate::infrastructure::http::client_config::HttpClientConfig;
+use crate::infrastructure::igdb::circuit_breaker::CircuitBreakerConfig;
+use crate::infrastructure::igdb::client::IgdbClient;
+use crate::infrastructure::igdb::fixtures::FixtureMode;
+use crate::infrastructure::igdb::retry::RetryPolicy;
+use crate::infrastructure::igdb::game_provider::IgdbGameProvider;
+use crate::infrastructure::igdb::game_suggestion_provider::IgdbGameSuggestionProvider;
+use crate::infrastructure::igdb::game_update_feed::IgdbGameUpdateFeed;
+use crate::infrastructure::igdb::image_url::IgdbImageUrls;
+use crate::infrastructure::igdb::platform_provider::IgdbPlatformProvider;
+use crate::infrastructure::igdb::reference_data_provider::IgdbReferenceDataProvider;
+use crate::infrastructure::igdb::release_provider::IgdbReleaseProvider;
+use crate::infrastructure::metadata::game_provider::HttpMetadataGameProvider;
+use crate::infrastructure::composite::composite_game_provider::{CompositeGameProvider, GameSource};
+use crate::infrastructure::cache::cached_platform_provider::CachedPlatformProvider;
+use crate::infrastructure::cache::cached_reference_data_provider::CachedReferenceDataProvider;
+use crate::infrastructure::cache::game_name_index::GameNameIndex;
+use crate::infrastructure::cache::indexed_game_suggestion_provider::IndexedGameSuggestionProvider;
+use crate::infrastructure::kafka::favorite_game_event_publisher::KafkaFavoriteGameEventPublisher;
+use crate::application::services::user_service::UserServiceImpl;
+use crate::application::services::game_service::GameServiceImpl;
+use crate::application::services::platform_service::PlatformServiceImpl;
+use crate::application::services::library_service::LibraryServiceImpl;
+use crate::application::services::reference_data_service::ReferenceDataServiceImpl;
+use crate::application::services::release_service::ReleaseServiceImpl;
+use crate::application::services::catalog_sync_service::CatalogSyncServiceImpl;
+use crate::application::ports::output::game_provider::GameProvider;
+use crate::application::ports::output::game_suggestion_provider::GameSuggestionProvider;
+use crate::application::ports::output::image_url_resolver::ImageUrlResolver;
+use crate::infrastructure::web::routes::{user_routes, game_routes, platform_routes, library_routes, health_routes, reference_data_routes, catalog_routes, release_routes};
+
+/// Everything the application reads from the environment, so the server and the
+/// integration tests build the same object graph.
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub igdb_client_id: String,
+    pub igdb_client_secret: String,
+    pub igdb_base_url: String,
+    pub igdb_auth_url: String,
+    pub igdb_requests_per_second: u32,
+    pub igdb_max_in_flight: usize,
+    pub igdb_retry_policy: RetryPolicy,
+    pub igdb_circuit_breaker: CircuitBreakerConfig,
+    pub igdb_fixtures: FixtureMode,
+    /// Timeouts, proxy, TLS and pooling of every outbound HTTP client.
+    pub http: HttpClientConfig,
+    pub game_cache_ttl: Duration,
+    pub game_cache_batch_size: usize,
+    /// JSON metadata API asked when IGDB fails and to fill fields IGDB leaves empty.
+    pub game_metadata_url: Option<String>,
+    pub game_metadata_timeout: Duration,
+    /// Serve games from the local catalog only (fill it with `sync-catalog`).
+    pub use_catalog: bool,
+    pub catalog_sync_page_size: i32,
+    pub platform_cache_refresh: Duration,
+    pub reference_data_cache_ttl: Duration,
+    pub games_batch_max_size: usize,
+    /// Local autocomplete matches below which IGDB (or the catalog) is also asked.
+    pub autocomplete_min_local_results: usize,
+    pub kafka_bootstrap_servers: String,
+    pub kafka_topic: String,
+}
+
+impl AppConfig {
+    /// Defaults for everything except the IGDB credentials and URLs.
+    pub fn new(igdb_client_id: String, igdb_client_secret: String, igdb_base_url: String, igdb_auth_url: String) -> Self {
+        Self {
+            igdb_client_id,
+            igdb_client_secret,
+            igdb_base_url,
+            igdb_auth_url,
+            igdb_requests_per_second: 4,
+            igdb_max_in_flight: 8,
+            igdb_retry_policy: RetryPolicy::default(),
+            igdb_circuit_breaker: CircuitBreakerConfig::default(),
+            igdb_fixtures: FixtureMode::Live,
+            http: HttpClientConfig::default(),
+            game_cache_ttl: Duration::from_secs(86_400),
+            game_cache_batch_size: 50,
+            game_metadata_url: None,
+            game_metadata_timeout: Duration::from_millis(2_000),
+            use_catalog: false,
+            catalog_sync_page_size: 500,
+            platform_cache_refresh: Duration::from_secs(3_600),
+            reference_data_cache_ttl: Duration::from_secs(86_400),
+            games_batch_max_size: 100,
+            autocomplete_min_local_results: 5,
+            kafka_bootstrap_servers: "localhost:9092".to_string(),
+            kafka_topic: "favorite-games-topic".to_string(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let igdb_fixtures = FixtureMode::parse(
+            &env::var("IGDB_MODE").unwrap_or_default(),
+            env::var("IGDB_FIXTURES_DIR").unwrap_or_else(|_| "fixtures/igdb".to_string()),
+        )
+        .expect("Invalid IGDB_MODE");
+
+        // Replay mode never talks to Twitch or IGDB, so credentials are optional there.
+        let (igdb_client_id, igdb_client_secret) = if igdb_fixtures.is_replay() {
+            (env::var("IGDB_CLIENT_ID").unwrap_or_default(), env::var("IGDB_CLIENT_SECRET").unwrap_or_default())
+        } else {
+            (
+                env::var("IGDB_CLIENT_ID").expect("IGDB_CLIENT_ID must be set"),
+                env::var("IGDB_CLIENT_SECRET").expect("IGDB_CLIENT_SECRET must be set"),
+            )
+        };
+        let igdb_base_url = env::var("IGDB_BASE_URL").unwrap_or_else(|_| "https://api.igdb.com/v4".to_string());
+        let igdb_auth_url = env::var("IGDB_AUTH_URL").unwrap_or_else(|_| "https://id.twitch.tv/oauth2/token".to_string());
+
+        let defaults = Self::new(igdb_client_id, igdb_client_secret, igdb_base_url, igdb_auth_url);
+        Self {
+            igdb_requests_per_second: env_or("IGDB_REQUESTS_PER_SECOND", defaults.igdb_requests_per_second),
+            igdb_max_in_flight: env_or("IGDB_MAX_IN_FLIGHT", defaults.igdb_max_in_flight),
+            igdb_retry_policy: RetryPolicy {
+                max_retries: env_or("IGDB_MAX_RETRIES", defaults.igdb_retry_policy.max_retries),
+                ..defaults.igdb_retry_policy
+            },
+            igdb_circuit_breaker: CircuitBreakerConfig {
+                failure_threshold: env_or("IGDB_BREAKER_FAILURE_THRESHOLD", defaults.igdb_circuit_breaker.failure_threshold),
+                open_duration: Duration::from_secs(env_or("IGDB_BREAKER_OPEN_SECONDS", defaults.igdb_circuit_breaker.open_duration.as_secs())),
+                half_open_max_probes: env_or("IGDB_BREAKER_HALF_OPEN_PROBES", defaults.igdb_circuit_breaker.half_open_max_probes),
+            },
+            igdb_fixtures,
+            http: HttpClientConfig {
+                connect_timeout: Duration::from_millis(env_or("OUTBOUND_HTTP_CONNECT_TIMEOUT_MS", defaults.http.connect_timeout.as_millis() as u64)),
+                // IGDB_REQUEST_TIMEOUT_MS predates the shared settings and is still honoured.
+                request_timeout: Duration::from_millis(env_or(
+                    "OUTBOUND_HTTP_REQUEST_TIMEOUT_MS",
+                    env_or("IGDB_REQUEST_TIMEOUT_MS", defaults.http.request_timeout.as_millis() as u64),
+                )),
+                overall_timeout: Duration::from_millis(env_or("OUTBOUND_HTTP_OVERALL_TIMEOUT_MS", defaults.http.overall_timeout.as_millis() as u64)),
+                proxy_url: env::var("OUTBOUND_HTTP_PROXY_URL").ok().filter(|url| !url.is_empty()),
+                ca_bundle_path: env::var("OUTBOUND_HTTP_CA_BUNDLE").ok().filter(|path| !path.is_empty()).map(Into::into),
+                pool_max_idle_per_host: env_or("OUTBOUND_HTTP_POOL_MAX_IDLE_PER_HOST", defaults.http.pool_max_idle_per_host),
+                pool_idle_timeout: Duration::from_secs(env_or("OUTBOUND_HTTP_POOL_IDLE_TIMEOUT_SECONDS", defaults.http.pool_idle_timeout.as_secs())),
+                user_agent: env::var("OUTBOUND_HTTP_USER_AGENT").unwrap_or(defaults.http.user_agent.clone()),
+            },
+            game_cache_ttl: Duration::from_secs(env_or("GAME_CACHE_TTL_SECONDS", defaults.game_cache_ttl.as_secs())),
+            game_cache_batch_size: env_or("GAME_CACHE_BATCH_SIZE", defaults.game_cache_batch_size),
+            game_metadata_url: env::var("GAME_METADATA_URL").ok().filter(|url| !url.is_empty()),
+            game_metadata_timeout: Duration::from_millis(env_or("GAME_METADATA_TIMEOUT_MS", defaults.game_metadata_timeout.as_millis() as u64)),
+            use_catalog: env::var("GAME_PROVIDER").as_deref() == Ok("catalog"),
+            catalog_sync_page_size: env_or("CATALOG_SYNC_PAGE_SIZE", defaults.catalog_sync_page_size),
+            platform_cache_refresh: Duration::from_secs(env_or("PLATFORM_CACHE_REFRESH_SECONDS", defaults.platform_cache_refresh.as_secs())),
+            reference_data_cache_ttl: Duration::from_secs(env_or("REFERENCE_DATA_CACHE_TTL_SECONDS", defaults.reference_data_cache_ttl.as_secs())),
+            games_batch_max_size: env_or("GAMES_BATCH_MAX_SIZE", defaults.games_batch_max_size),
+            autocomplete_min_local_results: env_or("AUTOCOMPLETE_MIN_LOCAL_RESULTS", defaults.autocomplete_min_local_results),
+            kafka_bootstrap_servers: env::var("KAFKA_BOOTSTRAP_SERVERS").unwrap_or(defaults.kafka_bootstrap_servers),
+            kafka_topic: env::var("KAFKA_TOPIC_FAVORITE_GAMES").unwrap_or(defaults.kafka_topic),
+            ..defaults
+        }
+    }
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    env::var(name).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+/// The outbound HTTP client shared by the IGDB client and the metadata API adapter.
+/// Fails on an invalid proxy URL or CA bundle.
+pub fn http_client(config: &AppConfig) -> Result<Client, String> {
+    config.http.build_client()
+}
+
+pub fn igdb_client(config: &AppConfig, http: Client) -> Arc<IgdbClient> {
+    Arc::new(
+        IgdbClient::new(
+            http,
+            config.igdb_client_id.clone(),
+            config.igdb_client_secret.clone(),
+            config.igdb_base_url.clone(),
+            config.igdb_auth_url.clone(),
+        )
+        .with_rate_limit(config.igdb_requests_per_second, config.igdb_max_in_flight)
+        .with_retry_policy(config.igdb_retry_policy)
+        .with_overall_timeout(config.http.overall_timeout)
+        .with_circuit_breaker(config.igdb_circuit_breaker)
+        .with_fixtures(config.igdb_fixtures.clone()),
+    )
+}
+
+pub fn catalog_sync_service(pool: &SqlitePool, igdb_client: Arc<IgdbClient>, config: &AppConfig) -> Arc<CatalogSyncServiceImpl> {
+    Arc::new(CatalogSyncServiceImpl::new(
+        Arc::new(IgdbGameUpdateFeed::new(igdb_client)),
+        Arc::new(SqliteGameCatalogRepository::new(pool.clone())),
+        config.catalog_sync_page_size,
+    ))
+}
+
+// `primary`, followed by the metadata API when one is configured.
+fn game_sources(config: &AppConfig, http: &Client, primary: GameSource) -> Arc<CompositeGameProvider> {
+    let mut sources = vec![primary];
+    if let Some(url) = &config.game_metadata_url {
+        let metadata = HttpMetadataGameProvider::new(http.clone(), url.clone(), config.game_metadata_timeout);
+        sources.push(GameSource::new("metadata", Arc::new(metadata)));
+    }
+    Arc::new(CompositeGameProvider::new(sources))
+}
+
+/// Wires adapters, services and routes. Expects a migrated pool; fails on an invalid
+/// outbound HTTP configuration.
+pub async fn build_app(pool: SqlitePool, config: &AppConfig) -> Result<Router, String> {
+    let user_repository = Arc::new(SqliteUserRepository::new(pool.clone()));
+    let library_repository = Arc::new(SqliteLibraryRepository::new(pool.clone()));
+
+    let http = http_client(config)?;
+    let igdb_client = igdb_client(config, http.clone());
+    igdb_client.token_provider().spawn_renewal();
+    let catalog_sync_service = catalog_sync_service(&pool, igdb_client.clone(), config);
+
+    let game_name_index = Arc::new(GameNameIndex::new());
+    let igdb_game_provider = Arc::new(IgdbGameProvider::new(igdb_client.clone()));
+    let game_cache = Arc::new(
+        SqliteCachedGameProvider::new(
+            game_sources(config, &http, GameSource::new("igdb", igdb_game_provider)),
+            pool.clone(),
+            config.game_cache_ttl,
+            config.game_cache_batch_size,
+        )
+        .with_name_index(game_name_index.clone())
+        .with_primary_source("igdb"),
+    );
+    match game_cache.index_cached_games().await {
+        Ok(indexed) => tracing::info!("Autocomplete index seeded with {} cached games", indexed),
+        Err(e) => tracing::warn!("Seeding the autocomplete index failed: {}", e),
+    }
+
+    let (game_provider, suggestion_source): (Arc<dyn GameProvider>, Arc<dyn GameSuggestionProvider>) = if config.use_catalog {
+        let catalog = Arc::new(SqliteCatalogGameProvider::new(pool.clone()));
+        (game_sources(config, &http, GameSource::new("catalog", catalog.clone())), catalog)
+    } else {
+        (game_cache.clone(), Arc::new(IgdbGameSuggestionProvider::new(igdb_client.clone())))
+    };
+    let game_suggestion_provider = Arc::new(IndexedGameSuggestionProvider::new(
+        suggestion_source,
+        game_name_index,
+        config.autocomplete_min_local_results,
+    ));
+
+    let igdb_platform_provider = Arc::new(IgdbPlatformProvider::new(igdb_client.clone()));
+    let platform_provider = Arc::new(CachedPlatformProvider::new(igdb_platform_provider));
+    if let Err(e) = platform_provider.refresh().await {
+        tracing::warn!("Initial platform cache load failed, serving platforms from IGDB until a refresh succeeds: {}", e);
+    }
+    platform_provider.spawn_refresh(config.platform_cache_refresh);
+
+    let reference_data_provider = Arc::new(CachedReferenceDataProvider::new(
+        Arc::new(IgdbReferenceDataProvider::new(igdb_client.clone())),
+        config.reference_data_cache_ttl,
+    ));
+
+    // Note: Kafka might fail if broker is not up. In production, handle this gracefully.
+    let favorite_game_event_publisher = match KafkaFavoriteGameEventPublisher::new(&config.kafka_bootstrap_servers, &config.kafka_topic) {
+        Ok(publisher) => Arc::new(publisher),
+        Err(e) => {
+            tracing::error!("Failed to initialize Kafka publisher: {}. Continuing without Kafka.", e);
+            // Create a dummy/mock publisher or panic depending on requirements.
+            // For now, we panic to alert the dev.
+            panic!("Kafka initialization failed: {}", e);
+        }
+    };
+
+    let user_service = Arc::new(UserServiceImpl::new(user_repository.clone()));
+    let game_service = Arc::new(GameServiceImpl::new(game_provider.clone(), library_repository.clone(), game_suggestion_provider));
+    let platform_service = Arc::new(PlatformServiceImpl::new(platform_provider.clone()));
+    let reference_data_service = Arc::new(ReferenceDataServiceImpl::new(reference_data_provider.clone()));
+    let release_service = Arc::new(ReleaseServiceImpl::new(Arc::new(IgdbReleaseProvider::new(igdb_client.clone()))));
+    let library_service = Arc::new(LibraryServiceImpl::new(
+        library_repository.clone(),
+        game_provider.clone(),
+        favorite_game_event_publisher.clone(),
+    ));
+
+    let image_urls: Arc<dyn ImageUrlResolver> = Arc::new(IgdbImageUrls);
+
+    let cors = CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods(Any)
+        .allow_headers(Any);
+
+    Ok(Router::new()
+        .merge(health_routes::routes(
+            game_cache,
+            platform_provider.clone(),
+            igdb_client.circuit_breaker(),
+            igdb_client.token_provider(),
+            igdb_client.request_coalescer(),
+        ))
+        .merge(user_routes::routes(user_service))
+        .merge(game_routes::routes(game_service, config.games_batch_max_size, image_urls.clone()))
+        .merge(platform_routes::routes(platform_service, image_urls))
+        .merge(reference_data_routes::routes(reference_data_service, reference_data_provider.ttl()))
+        .merge(release_routes::routes(release_service))
+        .merge(library_routes::routes(library_service))
+        .merge(catalog_routes::routes(catalog_sync_service))
+        .layer(cors))
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::domain::catalog::CatalogSyncProgress;
+use crate::domain::error::DomainError;
+
+#[async_trait]
+pub trait CatalogSyncService: Send + Sync {
+    /// Copies every game changed since the last sync into the local catalog.
+    /// With `full`, starts again from the beginning.
+    async fn sync(&self, full: bool) -> Result<CatalogSyncProgress, DomainError>;
+    async fn progress(&self) -> Result<CatalogSyncProgress, DomainError>;
+}
@@ -1,11 +1,19 @@
 use async_trait::async_trait;
-use crate::domain::game::Game;
+use uuid::Uuid;
+use crate::domain::game::{Game, GameBatch};
+use crate::domain::game_suggestion::GameSuggestion;
+use crate::domain::game_filter::GameFilter;
 use crate::domain::page::Page;
+use crate::domain::error::DomainError;
 
 #[async_trait]
 pub trait GameService: Send + Sync {
-    async fn search_games_by_name(&self, name: &str) -> Result<Vec<Game>, String>;
-    async fn get_game_by_id(&self, id: i64) -> Result<Option<Game>, String>;
-    async fn get_games_by_ids(&self, ids: &[i64]) -> Result<Vec<Game>, String>;
-    async fn filter_games(&self, filter: &str, sort: &str, limit: i32, offset: i32) -> Result<Page<Game>, String>;
+    async fn search_games_by_name(&self, name: &str) -> Result<Vec<Game>, DomainError>;
+    async fn get_game_by_id(&self, id: i64) -> Result<Option<Game>, DomainError>;
+    async fn get_games_by_ids(&self, ids: &[i64]) -> Result<GameBatch, DomainError>;
+    /// Juegos similares a `id`; si se indica un usuario, se omiten los que ya están en su biblioteca.
+    async fn get_similar_games(&self, id: i64, user_id: Option<Uuid>) -> Result<Option<Vec<Game>>, DomainError>;
+    /// Sugerencias ligeras para autocompletar mientras el usuario escribe.
+    async fn autocomplete(&self, query: &str, limit: usize) -> Result<Vec<GameSuggestion>, DomainError>;
+    async fn filter_games(&self, filter: &GameFilter, limit: i32, offset: i32) -> Result<Page<Game>, DomainError>;
 }
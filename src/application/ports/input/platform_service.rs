@@ -1,7 +1,11 @@
 use async_trait::async_trait;
 use crate::domain::platform::Platform;
+use crate::domain::platform_filter::PlatformFilter;
+use crate::domain::page::Page;
+use crate::domain::error::DomainError;
 
 #[async_trait]
 pub trait PlatformService: Send + Sync {
-    async fn list_platforms(&self) -> Result<Vec<Platform>, String>;
+    async fn get_platform_by_id(&self, id: i64) -> Result<Option<Platform>, DomainError>;
+    async fn list_platforms(&self, filter: &PlatformFilter, page: i32, size: i32) -> Result<Page<Platform>, DomainError>;
 }
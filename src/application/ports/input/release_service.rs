@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use crate::domain::release::{Release, ReleaseFilter};
+use crate::domain::page::Page;
+use crate::domain::error::DomainError;
+
+#[async_trait]
+pub trait ReleaseService: Send + Sync {
+    async fn list_releases(&self, filter: &ReleaseFilter, page: i32, size: i32) -> Result<Page<Release>, DomainError>;
+}
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use crate::domain::error::DomainError;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FavoriteGameEvent {
@@ -11,5 +12,5 @@ pub struct FavoriteGameEvent {
 
 #[async_trait]
 pub trait FavoriteGameEventPublisher: Send + Sync {
-    async fn publish_favorite_game_event(&self, event: FavoriteGameEvent) -> Result<(), String>;
+    async fn publish_favorite_game_event(&self, event: FavoriteGameEvent) -> Result<(), DomainError>;
 }
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::catalog::{CatalogGame, CatalogSyncProgress};
+use crate::domain::error::DomainError;
+
+#[async_trait]
+pub trait GameCatalogRepository: Send + Sync {
+    async fn load_progress(&self) -> Result<CatalogSyncProgress, DomainError>;
+    /// Records `progress` as the start of its run, unless another run is still active: one
+    /// that has written progress after `stale_before`. Returns whether the run started.
+    async fn try_start_run(&self, progress: &CatalogSyncProgress, stale_before: DateTime<Utc>) -> Result<bool, DomainError>;
+    /// Fails with `Conflict` once another run has taken over from `progress.run_id`.
+    async fn save_progress(&self, progress: &CatalogSyncProgress) -> Result<(), DomainError>;
+    /// Upserts a page of games and records `progress` atomically, so an interrupted
+    /// sync resumes right after the last stored page.
+    async fn save_page(&self, games: &[CatalogGame], progress: &CatalogSyncProgress) -> Result<(), DomainError>;
+}
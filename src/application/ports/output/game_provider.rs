@@ -1,11 +1,25 @@
 use async_trait::async_trait;
-use crate::domain::game::Game;
+use std::collections::HashMap;
+use crate::domain::game::{Game, TimeToBeat};
+use crate::domain::game_filter::GameFilter;
 use crate::domain::page::Page;
+use crate::domain::error::DomainError;
 
 #[async_trait]
 pub trait GameProvider: Send + Sync {
-    async fn find_by_external_id(&self, external_id: i64) -> Result<Option<Game>, String>;
-    async fn find_multiple_by_external_ids(&self, external_ids: &[i64]) -> Result<Vec<Game>, String>;
-    async fn search_by_name(&self, name: &str) -> Result<Vec<Game>, String>;
-    async fn filter_games(&self, filter: &str, sort: &str, limit: i32, offset: i32) -> Result<Page<Game>, String>;
+    async fn find_by_external_id(&self, external_id: i64) -> Result<Option<Game>, DomainError>;
+    /// Returns the games that exist, in the order of `external_ids`; unknown ids are skipped.
+    async fn find_multiple_by_external_ids(&self, external_ids: &[i64]) -> Result<Vec<Game>, DomainError>;
+    async fn search_by_name(&self, name: &str) -> Result<Vec<Game>, DomainError>;
+    /// Games the provider considers similar to `external_id`, or `None` if that game does not exist.
+    async fn find_similar_games(&self, external_id: i64) -> Result<Option<Vec<Game>>, DomainError>;
+    async fn filter_games(&self, filter: &GameFilter, limit: i32, offset: i32) -> Result<Page<Game>, DomainError>;
+    /// Time-to-beat of the given games, keyed by id; games without data are left out.
+    /// Providers that leave it out of batch lookups override this.
+    async fn find_time_to_beat(&self, external_ids: &[i64]) -> Result<HashMap<i64, TimeToBeat>, DomainError> {
+        Ok(self.find_multiple_by_external_ids(external_ids).await?
+            .into_iter()
+            .filter_map(|game| game.time_to_beat.filter(|t| !t.is_empty()).map(|time_to_beat| (game.id, time_to_beat)))
+            .collect())
+    }
 }
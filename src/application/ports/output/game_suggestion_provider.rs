@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use crate::domain::game_suggestion::GameSuggestion;
+use crate::domain::error::DomainError;
+
+#[async_trait]
+pub trait GameSuggestionProvider: Send + Sync {
+    /// At most `limit` games whose name matches `query`, best matches first.
+    async fn suggest(&self, query: &str, limit: usize) -> Result<Vec<GameSuggestion>, DomainError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::domain::catalog::{CatalogGame, SyncCursor};
+use crate::domain::error::DomainError;
+
+/// Source of game changes for the local catalog, ordered by modification time.
+#[async_trait]
+pub trait GameUpdateFeed: Send + Sync {
+    /// Returns up to `limit` games modified after `cursor`, oldest first.
+    async fn fetch_updated_since(&self, cursor: &SyncCursor, limit: i32) -> Result<Vec<CatalogGame>, DomainError>;
+    async fn count_updated_since(&self, cursor: &SyncCursor) -> Result<i64, DomainError>;
+}
@@ -0,0 +1,6 @@
+use crate::domain::image::{Image, ImageSize};
+
+pub trait ImageUrlResolver: Send + Sync {
+    /// Public URL of `image` in the given size.
+    fn image_url(&self, image: &Image, size: ImageSize) -> String;
+}
@@ -3,3 +3,9 @@ pub mod platform_provider;
 pub mod library_repository;
 pub mod user_repository;
 pub mod favorite_game_event_publisher;
+pub mod reference_data_provider;
+pub mod game_update_feed;
+pub mod game_catalog_repository;
+pub mod game_suggestion_provider;
+pub mod release_provider;
+pub mod image_url_resolver;
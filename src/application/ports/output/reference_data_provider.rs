@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+use crate::domain::reference_data::{ReferenceDataKind, ReferenceItem};
+use crate::domain::error::DomainError;
+
+#[async_trait]
+pub trait ReferenceDataProvider: Send + Sync {
+    async fn list(&self, kind: ReferenceDataKind) -> Result<Vec<ReferenceItem>, DomainError>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::domain::release::{Release, ReleaseFilter};
+use crate::domain::page::Page;
+use crate::domain::error::DomainError;
+
+#[async_trait]
+pub trait ReleaseProvider: Send + Sync {
+    /// Releases in the filter's date range, earliest first.
+    async fn find_releases(&self, filter: &ReleaseFilter, limit: i32, offset: i32) -> Result<Page<Release>, DomainError>;
+}
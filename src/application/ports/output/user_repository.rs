@@ -1,8 +1,9 @@
 use async_trait::async_trait;
 use crate::domain::user::User;
+use crate::domain::error::DomainError;
 
 #[async_trait]
 pub trait UserRepository: Send + Sync {
-    async fn save(&self, user: &User) -> Result<User, String>;
-    async fn find_by_email(&self, email: &str) -> Result<Option<User>, String>;
+    async fn save(&self, user: &User) -> Result<User, DomainError>;
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, DomainError>;
 }
@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+use crate::application::ports::input::catalog_sync_service::CatalogSyncService;
+use crate::application::ports::output::game_catalog_repository::GameCatalogRepository;
+use crate::application::ports::output::game_update_feed::GameUpdateFeed;
+use crate::domain::catalog::{CatalogSyncProgress, SyncCursor, SyncStatus};
+use crate::domain::error::DomainError;
+
+// A run that hasn't saved any progress for this long is assumed dead and can be taken over.
+const STALE_RUN_TIMEOUT: Duration = Duration::minutes(10);
+
+pub struct CatalogSyncServiceImpl {
+    pub game_update_feed: Arc<dyn GameUpdateFeed>,
+    pub game_catalog_repository: Arc<dyn GameCatalogRepository>,
+    pub page_size: i32,
+}
+
+impl CatalogSyncServiceImpl {
+    pub fn new(game_update_feed: Arc<dyn GameUpdateFeed>, game_catalog_repository: Arc<dyn GameCatalogRepository>, page_size: i32) -> Self {
+        Self { game_update_feed, game_catalog_repository, page_size: page_size.max(1) }
+    }
+
+    async fn sync_pages(&self, progress: &mut CatalogSyncProgress) -> Result<(), DomainError> {
+        progress.remaining_games = Some(self.game_update_feed.count_updated_since(&progress.cursor).await?);
+        self.game_catalog_repository.save_progress(progress).await?;
+
+        loop {
+            let games = self.game_update_feed.fetch_updated_since(&progress.cursor, self.page_size).await?;
+            let Some(last) = games.last() else {
+                return Ok(());
+            };
+
+            progress.cursor = SyncCursor { updated_at: last.updated_at, game_id: last.game.id };
+            progress.synced_games += games.len() as i64;
+            progress.remaining_games = progress.remaining_games.map(|remaining| (remaining - games.len() as i64).max(0));
+            self.game_catalog_repository.save_page(&games, progress).await?;
+            tracing::info!("Catalog sync stored {} games (cursor updated_at={})", progress.synced_games, progress.cursor.updated_at);
+
+            if (games.len() as i32) < self.page_size {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CatalogSyncService for CatalogSyncServiceImpl {
+    async fn sync(&self, full: bool) -> Result<CatalogSyncProgress, DomainError> {
+        let mut progress = self.game_catalog_repository.load_progress().await?;
+        if full {
+            progress.cursor = SyncCursor::default();
+        }
+        progress.status = SyncStatus::Running;
+        progress.run_id = Some(Uuid::new_v4());
+        progress.synced_games = 0;
+        progress.remaining_games = None;
+        progress.started_at = Some(Utc::now());
+        progress.finished_at = None;
+        progress.last_error = None;
+        if !self.game_catalog_repository.try_start_run(&progress, Utc::now() - STALE_RUN_TIMEOUT).await? {
+            return Err(DomainError::Conflict("A catalog sync is already running".to_string()));
+        }
+
+        let result = self.sync_pages(&mut progress).await;
+
+        progress.finished_at = Some(Utc::now());
+        match result {
+            Ok(()) => progress.status = SyncStatus::Completed,
+            Err(ref e) => {
+                progress.status = SyncStatus::Failed;
+                progress.last_error = Some(e.to_string());
+            }
+        }
+        self.game_catalog_repository.save_progress(&progress).await?;
+        result?;
+
+        self.game_catalog_repository.load_progress().await
+    }
+
+    async fn progress(&self) -> Result<CatalogSyncProgress, DomainError> {
+        self.game_catalog_repository.load_progress().await
+    }
+}
@@ -1,35 +1,85 @@
 use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use uuid::Uuid;
 use crate::application::ports::input::game_service::GameService;
 use crate::application::ports::output::game_provider::GameProvider;
-use crate::domain::game::Game;
+use crate::application::ports::output::game_suggestion_provider::GameSuggestionProvider;
+use crate::application::ports::output::library_repository::LibraryRepository;
+use crate::domain::game::{Game, GameBatch};
+use crate::domain::game_suggestion::GameSuggestion;
+use crate::domain::game_filter::GameFilter;
 use crate::domain::page::Page;
+use crate::domain::error::DomainError;
 
 pub struct GameServiceImpl {
     pub game_provider: Arc<dyn GameProvider>,
+    pub library_repository: Arc<dyn LibraryRepository>,
+    pub game_suggestion_provider: Arc<dyn GameSuggestionProvider>,
 }
 
 impl GameServiceImpl {
-    pub fn new(game_provider: Arc<dyn GameProvider>) -> Self {
-        Self { game_provider }
+    pub fn new(
+        game_provider: Arc<dyn GameProvider>,
+        library_repository: Arc<dyn LibraryRepository>,
+        game_suggestion_provider: Arc<dyn GameSuggestionProvider>,
+    ) -> Self {
+        Self { game_provider, library_repository, game_suggestion_provider }
     }
 }
 
 #[async_trait]
 impl GameService for GameServiceImpl {
-    async fn search_games_by_name(&self, name: &str) -> Result<Vec<Game>, String> {
+    async fn search_games_by_name(&self, name: &str) -> Result<Vec<Game>, DomainError> {
         self.game_provider.search_by_name(name).await
     }
 
-    async fn get_game_by_id(&self, id: i64) -> Result<Option<Game>, String> {
+    async fn get_game_by_id(&self, id: i64) -> Result<Option<Game>, DomainError> {
         self.game_provider.find_by_external_id(id).await
     }
 
-    async fn get_games_by_ids(&self, ids: &[i64]) -> Result<Vec<Game>, String> {
-        self.game_provider.find_multiple_by_external_ids(ids).await
+    async fn get_games_by_ids(&self, ids: &[i64]) -> Result<GameBatch, DomainError> {
+        let mut seen = HashSet::new();
+        let unique_ids: Vec<i64> = ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+
+        let mut found: HashMap<i64, Game> = self.game_provider.find_multiple_by_external_ids(&unique_ids).await?
+            .into_iter()
+            .map(|game| (game.id, game))
+            .collect();
+
+        let mut games = Vec::with_capacity(found.len());
+        let mut missing_ids = Vec::new();
+        for id in unique_ids {
+            match found.remove(&id) {
+                Some(game) => games.push(game),
+                None => missing_ids.push(id),
+            }
+        }
+
+        Ok(GameBatch { games, missing_ids })
+    }
+
+    async fn get_similar_games(&self, id: i64, user_id: Option<Uuid>) -> Result<Option<Vec<Game>>, DomainError> {
+        let Some(mut games) = self.game_provider.find_similar_games(id).await? else {
+            return Ok(None);
+        };
+
+        if let Some(user_id) = user_id {
+            let owned: HashSet<i64> = self.library_repository.find_by_user_id(user_id).await?
+                .into_iter()
+                .map(|user_game| user_game.game_id)
+                .collect();
+            games.retain(|game| !owned.contains(&game.id));
+        }
+
+        Ok(Some(games))
+    }
+
+    async fn autocomplete(&self, query: &str, limit: usize) -> Result<Vec<GameSuggestion>, DomainError> {
+        self.game_suggestion_provider.suggest(query, limit).await
     }
 
-    async fn filter_games(&self, filter: &str, sort: &str, limit: i32, offset: i32) -> Result<Page<Game>, String> {
-        self.game_provider.filter_games(filter, sort, limit, offset).await
+    async fn filter_games(&self, filter: &GameFilter, limit: i32, offset: i32) -> Result<Page<Game>, DomainError> {
+        self.game_provider.filter_games(filter, limit, offset).await
     }
 }
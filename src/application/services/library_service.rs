@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 use chrono::Utc;
@@ -7,8 +8,11 @@ use crate::application::ports::input::library_service::LibraryService;
 use crate::application::ports::output::library_repository::LibraryRepository;
 use crate::application::ports::output::game_provider::GameProvider;
 use crate::application::ports::output::favorite_game_event_publisher::{FavoriteGameEventPublisher, FavoriteGameEvent};
+use crate::domain::backlog::BacklogEstimate;
+use crate::domain::game::TimeToBeat;
 use crate::domain::user::{UserGame, GameStatus};
 use crate::domain::page::Page;
+use crate::domain::error::DomainError;
 
 pub struct LibraryServiceImpl {
     pub library_repository: Arc<dyn LibraryRepository>,
@@ -32,10 +36,10 @@ impl LibraryServiceImpl {
 
 #[async_trait]
 impl LibraryService for LibraryServiceImpl {
-    async fn upsert_game_in_library(&self, user_id: Uuid, game_id: i64, status: GameStatus) -> Result<Option<UserGame>, String> {
+    async fn upsert_game_in_library(&self, user_id: Uuid, game_id: i64, status: GameStatus) -> Result<Option<UserGame>, DomainError> {
         // Verify game exists
         if self.game_provider.find_by_external_id(game_id).await?.is_none() {
-            return Err(format!("Game with id {} not found", game_id));
+            return Err(DomainError::NotFound(format!("Game with id {} not found", game_id)));
         }
 
         let existing_entry_opt = self.library_repository.find_by_user_id_and_game_id(user_id, game_id).await?;
@@ -71,19 +75,19 @@ impl LibraryService for LibraryServiceImpl {
         }
     }
 
-    async fn list_user_library(&self, user_id: Uuid) -> Result<Vec<UserGame>, String> {
+    async fn list_user_library(&self, user_id: Uuid) -> Result<Vec<UserGame>, DomainError> {
         self.library_repository.find_by_user_id(user_id).await
     }
 
-    async fn get_user_game_status(&self, user_id: Uuid, game_id: i64) -> Result<Option<UserGame>, String> {
+    async fn get_user_game_status(&self, user_id: Uuid, game_id: i64) -> Result<Option<UserGame>, DomainError> {
         self.library_repository.find_by_user_id_and_game_id(user_id, game_id).await
     }
 
-    async fn remove_game_from_library(&self, user_id: Uuid, game_id: i64) -> Result<(), String> {
+    async fn remove_game_from_library(&self, user_id: Uuid, game_id: i64) -> Result<(), DomainError> {
         self.library_repository.delete_by_user_id_and_game_id(user_id, game_id).await
     }
 
-    async fn add_game_to_favorites(&self, user_id: Uuid, game_id: i64) -> Result<UserGame, String> {
+    async fn add_game_to_favorites(&self, user_id: Uuid, game_id: i64) -> Result<UserGame, DomainError> {
         let existing_entry_opt = self.library_repository.find_by_user_id_and_game_id(user_id, game_id).await?;
 
         let updated_user_game = if let Some(existing_entry) = existing_entry_opt {
@@ -97,7 +101,7 @@ impl LibraryService for LibraryServiceImpl {
             self.library_repository.update(&updated).await?
         } else {
             if self.game_provider.find_by_external_id(game_id).await?.is_none() {
-                return Err(format!("Game with id {} not found", game_id));
+                return Err(DomainError::NotFound(format!("Game with id {} not found", game_id)));
             }
             let new_favorite = UserGame {
                 user_id: user_id.to_string(),
@@ -120,9 +124,9 @@ impl LibraryService for LibraryServiceImpl {
         Ok(updated_user_game)
     }
 
-    async fn remove_game_from_favorites(&self, user_id: Uuid, game_id: i64) -> Result<(), String> {
+    async fn remove_game_from_favorites(&self, user_id: Uuid, game_id: i64) -> Result<(), DomainError> {
         let user_game = self.library_repository.find_by_user_id_and_game_id(user_id, game_id).await?
-            .ok_or_else(|| "Game not found in library".to_string())?;
+            .ok_or_else(|| DomainError::NotFound(format!("Game {} not found in user {} library", game_id, user_id)))?;
 
         if user_game.is_favorite {
             let event = FavoriteGameEvent {
@@ -149,7 +153,32 @@ impl LibraryService for LibraryServiceImpl {
         Ok(())
     }
 
-    async fn list_favorite_games(&self, user_id: Uuid, page: i32, size: i32) -> Result<Page<UserGame>, String> {
+    async fn list_favorite_games(&self, user_id: Uuid, page: i32, size: i32) -> Result<Page<UserGame>, DomainError> {
         self.library_repository.find_by_user_id_and_is_favorite_true(user_id, page, size).await
     }
+
+    async fn estimate_backlog(&self, user_id: Uuid) -> Result<BacklogEstimate, DomainError> {
+        let pending_ids: Vec<i64> = self.library_repository.find_by_user_id(user_id).await?
+            .into_iter()
+            .filter(|user_game| matches!(user_game.status, GameStatus::WantToPlay | GameStatus::Playing))
+            .map(|user_game| user_game.game_id)
+            .collect();
+
+        let mut time_to_beat: HashMap<i64, TimeToBeat> = self.game_provider.find_time_to_beat(&pending_ids).await?;
+
+        let mut estimate = BacklogEstimate { game_count: pending_ids.len(), ..Default::default() };
+        for game_id in pending_ids {
+            let Some(TimeToBeat { hastily, normally, completely }) = time_to_beat.remove(&game_id) else {
+                estimate.games_without_estimate.push(game_id);
+                continue;
+            };
+            // A missing pace is replaced by the closest one the game has, so partial data
+            // doesn't leave a game out of any of the totals.
+            estimate.hastily += hastily.or(normally).or(completely).unwrap_or_default();
+            estimate.normally += normally.or(hastily).or(completely).unwrap_or_default();
+            estimate.completely += completely.or(normally).or(hastily).unwrap_or_default();
+        }
+
+        Ok(estimate)
+    }
 }
@@ -2,3 +2,6 @@ pub mod game_service;
 pub mod platform_service;
 pub mod user_service;
 pub mod library_service;
+pub mod reference_data_service;
+pub mod catalog_sync_service;
+pub mod release_service;
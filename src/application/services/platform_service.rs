@@ -3,6 +3,9 @@ use std::sync::Arc;
 use crate::application::ports::input::platform_service::PlatformService;
 use crate::application::ports::output::platform_provider::PlatformProvider;
 use crate::domain::platform::Platform;
+use crate::domain::platform_filter::PlatformFilter;
+use crate::domain::page::Page;
+use crate::domain::error::DomainError;
 
 pub struct PlatformServiceImpl {
     pub platform_provider: Arc<dyn PlatformProvider>,
@@ -16,7 +19,11 @@ impl PlatformServiceImpl {
 
 #[async_trait]
 impl PlatformService for PlatformServiceImpl {
-    async fn list_platforms(&self) -> Result<Vec<Platform>, String> {
-        self.platform_provider.list_platforms().await
+    async fn get_platform_by_id(&self, id: i64) -> Result<Option<Platform>, DomainError> {
+        self.platform_provider.find_by_id(id).await
+    }
+
+    async fn list_platforms(&self, filter: &PlatformFilter, page: i32, size: i32) -> Result<Page<Platform>, DomainError> {
+        self.platform_provider.list_platforms(filter, size, page * size).await
     }
 }
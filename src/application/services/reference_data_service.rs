@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use crate::application::ports::input::reference_data_service::ReferenceDataService;
+use crate::application::ports::output::reference_data_provider::ReferenceDataProvider;
+use crate::domain::reference_data::{ReferenceDataKind, ReferenceItem};
+use crate::domain::error::DomainError;
+
+pub struct ReferenceDataServiceImpl {
+    pub reference_data_provider: Arc<dyn ReferenceDataProvider>,
+}
+
+impl ReferenceDataServiceImpl {
+    pub fn new(reference_data_provider: Arc<dyn ReferenceDataProvider>) -> Self {
+        Self { reference_data_provider }
+    }
+}
+
+#[async_trait]
+impl ReferenceDataService for ReferenceDataServiceImpl {
+    async fn list(&self, kind: ReferenceDataKind) -> Result<Vec<ReferenceItem>, DomainError> {
+        self.reference_data_provider.list(kind).await
+    }
+}
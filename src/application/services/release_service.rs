@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use crate::application::ports::input::release_service::ReleaseService;
+use crate::application::ports::output::release_provider::ReleaseProvider;
+use crate::domain::release::{Release, ReleaseFilter};
+use crate::domain::page::Page;
+use crate::domain::error::DomainError;
+
+pub struct ReleaseServiceImpl {
+    pub release_provider: Arc<dyn ReleaseProvider>,
+}
+
+impl ReleaseServiceImpl {
+    pub fn new(release_provider: Arc<dyn ReleaseProvider>) -> Self {
+        Self { release_provider }
+    }
+}
+
+#[async_trait]
+impl ReleaseService for ReleaseServiceImpl {
+    async fn list_releases(&self, filter: &ReleaseFilter, page: i32, size: i32) -> Result<Page<Release>, DomainError> {
+        self.release_provider.find_releases(filter, size, page * size).await
+    }
+}
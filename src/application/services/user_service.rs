@@ -9,6 +9,7 @@ use crate::application::ports::input::user_service::UserService;
 use crate::application::ports::output::user_repository::UserRepository;
 use crate::domain::user::{User, LoginResult};
 use crate::domain::auth::Claims;
+use crate::domain::error::DomainError;
 
 // TODO: Move this to a configuration file or environment variable
 const JWT_SECRET: &[u8] = b"secret";
@@ -25,14 +26,14 @@ impl UserServiceImpl {
 
 #[async_trait]
 impl UserService for UserServiceImpl {
-    async fn register_user(&self, username: &str, email: &str, password: &str) -> Result<User, String> {
+    async fn register_user(&self, username: &str, email: &str, password: &str) -> Result<User, DomainError> {
         // Check if user already exists
-        if let Ok(Some(_)) = self.user_repository.find_by_email(email).await {
-            return Err(format!("El email '{}' ya está registrado.", email));
+        if self.user_repository.find_by_email(email).await?.is_some() {
+            return Err(DomainError::Conflict(format!("El email '{}' ya está registrado.", email)));
         }
 
         // Hash password
-        let hashed_password = hash(password, DEFAULT_COST).map_err(|e| e.to_string())?;
+        let hashed_password = hash(password, DEFAULT_COST).map_err(|e| DomainError::Internal(e.to_string()))?;
 
         // Create new user
         let new_user = User {
@@ -46,16 +47,14 @@ impl UserService for UserServiceImpl {
         self.user_repository.save(&new_user).await
     }
 
-    async fn login_user(&self, email: &str, password: &str) -> Result<Option<LoginResult>, String> {
+    async fn login_user(&self, email: &str, password: &str) -> Result<LoginResult, DomainError> {
         // Find user by email
-        let user = match self.user_repository.find_by_email(email).await {
-            Ok(Some(user)) => user,
-            _ => return Ok(None),
-        };
+        let user = self.user_repository.find_by_email(email).await?
+            .ok_or_else(|| DomainError::Unauthorized("Invalid credentials".to_string()))?;
 
         // Verify password
         if !verify(password, &user.password).unwrap_or(false) {
-            return Ok(None);
+            return Err(DomainError::Unauthorized("Invalid credentials".to_string()));
         }
 
         // Generate JWT
@@ -71,12 +70,12 @@ impl UserService for UserServiceImpl {
         };
 
         let token = encode(&Header::default(), &claims, &EncodingKey::from_secret(JWT_SECRET))
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| DomainError::Internal(e.to_string()))?;
 
-        Ok(Some(LoginResult {
+        Ok(LoginResult {
             token,
             user: user.clone(),
             username: user.username.clone(),
-        }))
+        })
     }
 }
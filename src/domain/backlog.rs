@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Estimación del tiempo necesario para terminar los juegos pendientes de un usuario
+/// (los marcados como WANT_TO_PLAY o PLAYING).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BacklogEstimate {
+    /// Número de juegos pendientes.
+    #[serde(rename = "game_count")]
+    pub game_count: usize,
+    /// Suma en segundos yendo directo a la historia principal.
+    pub hastily: i64,
+    /// Suma en segundos a un ritmo normal.
+    pub normally: i64,
+    /// Suma en segundos completando todo el contenido.
+    pub completely: i64,
+    /// Juegos pendientes sin ningún dato de duración, que no suman a los totales.
+    #[serde(rename = "games_without_estimate")]
+    pub games_without_estimate: Vec<i64>,
+}
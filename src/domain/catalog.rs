@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::game::Game;
+
+/// Un juego del catálogo local, con los datos necesarios para filtrarlo
+/// sin consultar al proveedor externo.
+#[derive(Debug, Clone)]
+pub struct CatalogGame {
+    pub game: Game,
+    /// IDs de las plataformas del juego (el juego solo guarda sus nombres).
+    pub platform_ids: Vec<i64>,
+    /// Marca de tiempo (Unix) de la última modificación en el proveedor externo.
+    pub updated_at: i64,
+}
+
+/// Posición de la sincronización: el último juego guardado, en orden de `updated_at` e ID.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncCursor {
+    pub updated_at: i64,
+    pub game_id: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SyncStatus {
+    Idle,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// Estado de la sincronización del catálogo local.
+#[derive(Debug, Clone)]
+pub struct CatalogSyncProgress {
+    pub status: SyncStatus,
+    /// Ejecución que está escribiendo el progreso; solo ella puede actualizarlo.
+    pub run_id: Option<Uuid>,
+    /// Punto desde el que continuará la próxima sincronización.
+    pub cursor: SyncCursor,
+    /// Juegos guardados en la ejecución actual o en la última.
+    pub synced_games: i64,
+    /// Juegos pendientes según el proveedor al empezar la ejecución, descontando los ya guardados.
+    pub remaining_games: Option<i64>,
+    /// Total de juegos en el catálogo local.
+    pub total_games: i64,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+/// Errores del dominio devueltos por los puertos y servicios de la aplicación.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DomainError {
+    /// El recurso solicitado no existe.
+    #[error("{0}")]
+    NotFound(String),
+    /// El recurso entra en conflicto con el estado actual (ej. email duplicado).
+    #[error("{0}")]
+    Conflict(String),
+    /// Los datos de entrada no son válidos.
+    #[error("{0}")]
+    Validation(String),
+    /// Un servicio externo (ej. IGDB) ha fallado o ha devuelto una respuesta inválida.
+    #[error("Upstream error: {0}")]
+    Upstream(String),
+    /// El llamante no está autorizado para realizar la operación.
+    #[error("{0}")]
+    Unauthorized(String),
+    /// Error inesperado dentro de la aplicación.
+    #[error("Internal error: {0}")]
+    Internal(String),
+}
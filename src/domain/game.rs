@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use chrono::NaiveDate;
-use crate::domain::artwork::Artwork;
+use crate::domain::image::Image;
 
 /// Representa un videojuego en el dominio de la aplicación.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,9 +24,8 @@ pub struct Game {
     /// Puntuación media de los usuarios (0-100).
     pub rating: Option<f64>,
 
-    /// La URL de la imagen de portada.
-    #[serde(rename = "cover_image_url")]
-    pub cover_image_url: Option<String>,
+    /// La imagen de portada.
+    pub cover: Option<Image>,
 
     /// Lista de nombres de plataformas donde está disponible.
     pub platforms: Vec<String>,
@@ -37,9 +36,126 @@ pub struct Game {
     /// Lista de URLs de videos relacionados (trailers, gameplays).
     pub videos: Vec<String>,
 
-    /// Lista de URLs de capturas de pantalla.
-    pub screenshots: Vec<String>,
+    /// Lista de capturas de pantalla.
+    pub screenshots: Vec<Image>,
 
     /// Una lista de artworks asociados al juego.
-    pub artworks: Vec<Artwork>,
+    pub artworks: Vec<Image>,
+
+    /// Compañías que han participado en el juego y su rol.
+    #[serde(rename = "involved_companies", default)]
+    pub involved_companies: Vec<InvolvedCompany>,
+
+    /// Franquicias a las que pertenece el juego.
+    #[serde(default)]
+    pub franchises: Vec<String>,
+
+    /// Colecciones (series) a las que pertenece el juego.
+    #[serde(default)]
+    pub collections: Vec<String>,
+
+    /// Temáticas del juego (ej. Fantasy, Sci-fi).
+    #[serde(default)]
+    pub themes: Vec<String>,
+
+    /// Modos de juego (ej. Single player, Multiplayer).
+    #[serde(rename = "game_modes", default)]
+    pub game_modes: Vec<String>,
+
+    /// Perspectivas del jugador (ej. First person).
+    #[serde(rename = "player_perspectives", default)]
+    pub player_perspectives: Vec<String>,
+
+    /// Clasificaciones por edad (ej. PEGI 18).
+    #[serde(rename = "age_ratings", default)]
+    pub age_ratings: Vec<AgeRating>,
+
+    /// Sitios web relacionados con el juego.
+    #[serde(default)]
+    pub websites: Vec<Website>,
+
+    /// Nombres alternativos o traducidos del juego.
+    #[serde(rename = "alternative_names", default)]
+    pub alternative_names: Vec<String>,
+
+    /// Identificadores de juegos similares, en el orden de relevancia del proveedor.
+    #[serde(rename = "similar_game_ids", default)]
+    pub similar_game_ids: Vec<i64>,
+
+    /// Tiempo estimado para terminar el juego. `None` si no se ha consultado; vacío si se
+    /// consultó y el proveedor no tiene datos.
+    #[serde(rename = "time_to_beat", default)]
+    pub time_to_beat: Option<TimeToBeat>,
+
+    /// Fuentes de las que proceden los datos: la primera sirvió el juego y las
+    /// siguientes completaron campos que le faltaban.
+    #[serde(default)]
+    pub sources: Vec<String>,
+
+    /// Si son los últimos datos conocidos, servidos porque no se han podido refrescar.
+    #[serde(skip)]
+    pub stale: bool,
+}
+
+/// Tiempos estimados para terminar un juego, en segundos, según los jugadores.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeToBeat {
+    /// Yendo directo a la historia principal.
+    pub hastily: Option<i64>,
+    /// A un ritmo normal, con algo de contenido secundario.
+    pub normally: Option<i64>,
+    /// Completando todo el contenido.
+    pub completely: Option<i64>,
+}
+
+impl TimeToBeat {
+    pub fn is_empty(&self) -> bool {
+        self.hastily.is_none() && self.normally.is_none() && self.completely.is_none()
+    }
+}
+
+/// Una compañía que ha participado en el desarrollo o publicación de un juego.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvolvedCompany {
+    /// El nombre de la compañía.
+    pub name: String,
+    /// Si la compañía ha desarrollado el juego.
+    pub developer: bool,
+    /// Si la compañía ha publicado el juego.
+    pub publisher: bool,
+    /// Si la compañía ha realizado un port del juego.
+    pub porting: bool,
+    /// Si la compañía ha dado soporte al desarrollo.
+    pub supporting: bool,
+}
+
+/// Una clasificación por edad emitida por un organismo (ej. PEGI, ESRB).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgeRating {
+    /// El organismo que emite la clasificación.
+    pub organization: String,
+    /// La clasificación asignada (ej. "18", "M").
+    pub rating: String,
+}
+
+/// Un sitio web relacionado con un juego.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Website {
+    /// La URL del sitio.
+    pub url: String,
+    /// El tipo de sitio (ej. official, steam, wikipedia).
+    pub category: Option<String>,
+    /// Si IGDB considera el sitio verificado.
+    pub trusted: bool,
+}
+
+/// Resultado de una búsqueda de juegos por lote de IDs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameBatch {
+    /// Juegos encontrados, en el mismo orden que los IDs solicitados.
+    pub games: Vec<Game>,
+
+    /// IDs solicitados que no se han podido resolver.
+    #[serde(rename = "missing_ids")]
+    pub missing_ids: Vec<i64>,
 }
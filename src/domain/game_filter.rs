@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// Criterios de filtrado de juegos, independientes del proveedor externo.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameFilter {
+    /// IDs de plataformas; el juego debe estar disponible en al menos una.
+    pub platform_ids: Vec<i64>,
+    /// Nombres de géneros; el juego debe pertenecer a al menos uno.
+    pub genres: Vec<String>,
+    /// Año de lanzamiento mínimo (incluido).
+    pub release_year_from: Option<i32>,
+    /// Año de lanzamiento máximo (incluido).
+    pub release_year_to: Option<i32>,
+    /// Puntuación mínima (0-10).
+    pub rating_min: Option<f64>,
+    /// Puntuación máxima (0-10).
+    pub rating_max: Option<f64>,
+    /// Texto que debe contener el nombre del juego (sin distinguir mayúsculas).
+    pub name_contains: Option<String>,
+    /// Criterio de ordenación de los resultados.
+    pub sort: Option<GameSort>,
+}
+
+/// Ordenación de resultados sobre un campo permitido.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameSort {
+    pub field: GameSortField,
+    pub direction: SortDirection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GameSortField {
+    Name,
+    ReleaseDate,
+    Rating,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
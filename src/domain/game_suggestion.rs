@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use chrono::Datelike;
+use crate::domain::game::Game;
+use crate::domain::image::Image;
+
+/// Resultado ligero de autocompletado: lo justo para pintar una sugerencia.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSuggestion {
+    /// El identificador único del juego.
+    pub id: i64,
+    /// El nombre del juego.
+    pub name: String,
+    /// El año de lanzamiento, si se conoce.
+    pub release_year: Option<i32>,
+    /// La imagen de portada.
+    pub cover: Option<Image>,
+}
+
+impl From<&Game> for GameSuggestion {
+    fn from(game: &Game) -> Self {
+        Self {
+            id: game.id,
+            name: game.name.clone(),
+            release_year: game.release_date.map(|date| date.year()),
+            cover: game.cover.clone(),
+        }
+    }
+}
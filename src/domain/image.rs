@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Representa una imagen de un juego (portada, captura de pantalla o artwork).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Image {
+    /// El ID de la imagen en IGDB.
+    pub id: i64,
+    /// El identificador de la imagen en los servidores de imágenes de IGDB,
+    /// a partir del cual se construyen las URLs de cualquier tamaño.
+    #[serde(rename = "image_id")]
+    pub image_id: String,
+    /// El ancho de la imagen original en píxeles.
+    pub width: Option<i32>,
+    /// La altura de la imagen original en píxeles.
+    pub height: Option<i32>,
+    /// Indica si la imagen tiene canal alfa.
+    #[serde(rename = "alpha_channel")]
+    pub alpha_channel: bool,
+    /// Indica si la imagen es animada.
+    pub animated: bool,
+    /// El checksum de la imagen.
+    pub checksum: Option<String>,
+    /// El tipo de artwork (solo para artworks).
+    #[serde(rename = "artwork_type")]
+    pub artwork_type: Option<i64>,
+}
+
+/// Tamaños predefinidos en los que se sirven las imágenes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImagePreset {
+    CoverSmall,
+    CoverBig,
+    ScreenshotMed,
+    ScreenshotBig,
+    ScreenshotHuge,
+    LogoMed,
+    Thumb,
+    Micro,
+    P720,
+    P1080,
+}
+
+impl ImagePreset {
+    const ALL: [ImagePreset; 10] = [
+        ImagePreset::CoverSmall,
+        ImagePreset::CoverBig,
+        ImagePreset::ScreenshotMed,
+        ImagePreset::ScreenshotBig,
+        ImagePreset::ScreenshotHuge,
+        ImagePreset::LogoMed,
+        ImagePreset::Thumb,
+        ImagePreset::Micro,
+        ImagePreset::P720,
+        ImagePreset::P1080,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ImagePreset::CoverSmall => "cover_small",
+            ImagePreset::CoverBig => "cover_big",
+            ImagePreset::ScreenshotMed => "screenshot_med",
+            ImagePreset::ScreenshotBig => "screenshot_big",
+            ImagePreset::ScreenshotHuge => "screenshot_huge",
+            ImagePreset::LogoMed => "logo_med",
+            ImagePreset::Thumb => "thumb",
+            ImagePreset::Micro => "micro",
+            ImagePreset::P720 => "720p",
+            ImagePreset::P1080 => "1080p",
+        }
+    }
+}
+
+/// Un tamaño predefinido y si se quiere su variante de doble resolución (`_2x`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageSize {
+    preset: ImagePreset,
+    retina: bool,
+}
+
+impl ImageSize {
+    pub const COVER: ImageSize = ImageSize::new(ImagePreset::CoverBig);
+    pub const SCREENSHOT: ImageSize = ImageSize::new(ImagePreset::ScreenshotBig);
+    pub const ARTWORK: ImageSize = ImageSize::new(ImagePreset::P1080);
+    pub const LOGO: ImageSize = ImageSize::new(ImagePreset::LogoMed);
+
+    pub const fn new(preset: ImagePreset) -> Self {
+        Self { preset, retina: false }
+    }
+
+    pub const fn retina(self) -> Self {
+        Self { retina: true, ..self }
+    }
+}
+
+impl fmt::Display for ImageSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.preset.as_str())?;
+        if self.retina {
+            write!(f, "_2x")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ImageSize {
+    type Err = String;
+
+    /// Interpreta los nombres tal como los escribe IGDB, p. ej. `cover_big` o `720p_2x`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (name, retina) = match value.strip_suffix("_2x") {
+            Some(name) => (name, true),
+            None => (value, false),
+        };
+
+        ImagePreset::ALL
+            .into_iter()
+            .find(|preset| preset.as_str() == name)
+            .map(|preset| if retina { ImageSize::new(preset).retina() } else { ImageSize::new(preset) })
+            .ok_or_else(|| {
+                let names = ImagePreset::ALL.iter().map(|p| p.as_str()).collect::<Vec<_>>().join(", ");
+                format!("Unknown image size '{}'; expected one of: {} (optionally with a _2x suffix)", value, names)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_preset_names() {
+        assert_eq!("cover_small".parse(), Ok(ImageSize::new(ImagePreset::CoverSmall)));
+        assert_eq!("1080p_2x".parse(), Ok(ImageSize::new(ImagePreset::P1080).retina()));
+        assert!("t_cover_big".parse::<ImageSize>().is_err());
+        assert!("huge".parse::<ImageSize>().is_err());
+    }
+}
@@ -1,6 +1,14 @@
 pub mod game;
-pub mod artwork;
+pub mod image;
 pub mod platform;
 pub mod user;
 pub mod auth;
 pub mod page;
+pub mod error;
+pub mod game_filter;
+pub mod platform_filter;
+pub mod reference_data;
+pub mod catalog;
+pub mod game_suggestion;
+pub mod release;
+pub mod backlog;
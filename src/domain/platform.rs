@@ -1,4 +1,6 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use crate::domain::image::Image;
 
 /// Representa una plataforma de videojuegos en el dominio.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +14,43 @@ pub struct Platform {
     /// El tipo de plataforma.
     #[serde(rename = "platform_type")]
     pub platform_type: PlatformType,
+    /// La abreviatura de la plataforma (ej. "PS4").
+    #[serde(default)]
+    pub abbreviation: Option<String>,
+    /// La familia a la que pertenece la plataforma (ej. PlayStation).
+    #[serde(default)]
+    pub family: Option<PlatformFamily>,
+    /// El logotipo de la plataforma.
+    #[serde(default)]
+    pub logo: Option<Image>,
+    /// Las versiones o revisiones de hardware de la plataforma.
+    #[serde(default)]
+    pub versions: Vec<PlatformVersion>,
+}
+
+/// Familia de plataformas de un mismo fabricante o linaje.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlatformFamily {
+    pub id: i64,
+    pub name: String,
+}
+
+/// Una versión concreta de una plataforma (ej. PlayStation 4 Pro).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlatformVersion {
+    pub id: i64,
+    pub name: String,
+    /// Fechas de lanzamiento de la versión en cada región.
+    #[serde(rename = "release_dates")]
+    pub release_dates: Vec<PlatformReleaseDate>,
+}
+
+/// Fecha de lanzamiento de una versión de plataforma en una región.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlatformReleaseDate {
+    pub date: Option<NaiveDate>,
+    /// La región del lanzamiento (ej. "europe", "north_america").
+    pub region: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
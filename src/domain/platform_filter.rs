@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::platform::PlatformType;
+
+/// Criterios de filtrado de plataformas, independientes del proveedor externo.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlatformFilter {
+    /// Tipos de plataforma; la plataforma debe ser de alguno de ellos.
+    pub platform_types: Vec<PlatformType>,
+    /// Generación de la plataforma.
+    pub generation: Option<i32>,
+    /// ID de la familia de plataformas.
+    pub family_id: Option<i64>,
+}
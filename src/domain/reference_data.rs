@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Tipos de datos de referencia con los que se clasifican los juegos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReferenceDataKind {
+    Genre,
+    Theme,
+    GameMode,
+}
+
+/// Un valor de referencia (ej. el género "Shooter").
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReferenceItem {
+    /// El ID del valor en el proveedor externo.
+    pub id: i64,
+    /// El nombre del valor, tal y como se usa en los filtros de juegos.
+    pub name: String,
+}
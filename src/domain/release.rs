@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+
+/// Con qué precisión se conoce una fecha de lanzamiento.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DatePrecision {
+    /// Día exacto.
+    Day,
+    /// Solo mes y año.
+    Month,
+    /// Solo trimestre y año.
+    Quarter,
+    /// Solo año.
+    Year,
+    /// Fecha por determinar.
+    Tbd,
+}
+
+/// Regiones de lanzamiento tal y como las define IGDB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[repr(i32)]
+pub enum ReleaseRegion {
+    Europe = 1,
+    NorthAmerica = 2,
+    Australia = 3,
+    NewZealand = 4,
+    Japan = 5,
+    China = 6,
+    Asia = 7,
+    Worldwide = 8,
+    Korea = 9,
+    Brazil = 10,
+}
+
+impl From<ReleaseRegion> for i32 {
+    fn from(region: ReleaseRegion) -> Self {
+        region as i32
+    }
+}
+
+/// Lanzamiento de un juego en una plataforma y región concretas.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Release {
+    /// El ID de la fecha de lanzamiento en el proveedor externo.
+    pub id: i64,
+    /// El ID del juego.
+    pub game_id: i64,
+    /// El nombre del juego.
+    pub game_name: Option<String>,
+    /// El ID de la plataforma.
+    pub platform_id: Option<i64>,
+    /// El nombre de la plataforma.
+    pub platform_name: Option<String>,
+    /// La región (ej. "europe", "north_america").
+    pub region: Option<String>,
+    /// La fecha; con una precisión menor que `Day` indica el inicio del periodo.
+    pub date: Option<NaiveDate>,
+    /// La precisión de `date`.
+    pub precision: DatePrecision,
+    /// La fecha tal y como se muestra (ej. "Q3 2025", "TBD").
+    pub human: Option<String>,
+}
+
+/// Criterios de búsqueda del calendario de lanzamientos.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseFilter {
+    /// Primer día incluido.
+    pub from: NaiveDate,
+    /// Último día incluido.
+    pub to: NaiveDate,
+    /// Plataformas; el lanzamiento debe ser en alguna de ellas.
+    pub platform_ids: Vec<i64>,
+    /// Región del lanzamiento.
+    pub region: Option<ReleaseRegion>,
+}
@@ -0,0 +1,213 @@
+use async_trait::async_trait;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use crate::application::ports::output::platform_provider::PlatformProvider;
+use crate::domain::error::DomainError;
+use crate::domain::page::Page;
+use crate::domain::platform::Platform;
+use crate::domain::platform_filter::PlatformFilter;
+
+// IGDB returns at most 500 records per query.
+const LOAD_PAGE_SIZE: i32 = 500;
+
+/// State of the platform snapshot as reported by the health endpoint.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PlatformCacheStatus {
+    pub platforms: usize,
+    #[serde(rename = "snapshotAgeSeconds")]
+    pub snapshot_age_seconds: Option<u64>,
+    #[serde(rename = "lastRefreshError")]
+    pub last_refresh_error: Option<String>,
+}
+
+struct Snapshot {
+    platforms: Arc<Vec<Platform>>,
+    loaded_at: Instant,
+}
+
+/// `PlatformProvider` decorator that serves every lookup from an in-memory snapshot of
+/// the full platform list. The snapshot is replaced only by a successful refresh, so a
+/// failing upstream keeps the last good data. Until the first load succeeds, calls go
+/// straight to the wrapped provider.
+pub struct CachedPlatformProvider {
+    inner: Arc<dyn PlatformProvider>,
+    snapshot: RwLock<Option<Snapshot>>,
+    last_refresh_error: RwLock<Option<String>>,
+}
+
+impl CachedPlatformProvider {
+    pub fn new(inner: Arc<dyn PlatformProvider>) -> Self {
+        Self {
+            inner,
+            snapshot: RwLock::new(None),
+            last_refresh_error: RwLock::new(None),
+        }
+    }
+
+    /// Reloads every platform from the wrapped provider and swaps the snapshot.
+    pub async fn refresh(&self) -> Result<(), DomainError> {
+        match self.load_all().await {
+            Ok(platforms) => {
+                tracing::info!("Platform cache refreshed with {} platforms", platforms.len());
+                *self.snapshot.write().unwrap() = Some(Snapshot {
+                    platforms: Arc::new(platforms),
+                    loaded_at: Instant::now(),
+                });
+                *self.last_refresh_error.write().unwrap() = None;
+                Ok(())
+            }
+            Err(e) => {
+                tracing::warn!("Platform cache refresh failed, keeping previous snapshot: {}", e);
+                *self.last_refresh_error.write().unwrap() = Some(e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    /// Refreshes the snapshot every `interval` until the returned task is aborted.
+    pub fn spawn_refresh(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let cache = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+            loop {
+                ticker.tick().await;
+                // Failures are logged and recorded by `refresh`.
+                let _ = cache.refresh().await;
+            }
+        })
+    }
+
+    pub fn status(&self) -> PlatformCacheStatus {
+        let snapshot = self.snapshot.read().unwrap();
+        PlatformCacheStatus {
+            platforms: snapshot.as_ref().map(|s| s.platforms.len()).unwrap_or(0),
+            snapshot_age_seconds: snapshot.as_ref().map(|s| s.loaded_at.elapsed().as_secs()),
+            last_refresh_error: self.last_refresh_error.read().unwrap().clone(),
+        }
+    }
+
+    async fn load_all(&self) -> Result<Vec<Platform>, DomainError> {
+        let filter = PlatformFilter::default();
+        let mut platforms = Vec::new();
+
+        loop {
+            let page = self.inner.list_platforms(&filter, LOAD_PAGE_SIZE, platforms.len() as i32).await?;
+            let received = page.content.len();
+            platforms.extend(page.content);
+
+            if received < LOAD_PAGE_SIZE as usize || platforms.len() as i64 >= page.total_elements {
+                return Ok(platforms);
+            }
+        }
+    }
+
+    fn platforms(&self) -> Option<Arc<Vec<Platform>>> {
+        self.snapshot.read().unwrap().as_ref().map(|s| Arc::clone(&s.platforms))
+    }
+}
+
+fn matches(platform: &Platform, filter: &PlatformFilter) -> bool {
+    (filter.platform_types.is_empty() || filter.platform_types.contains(&platform.platform_type))
+        && filter.generation.is_none_or(|generation| platform.generation == Some(generation))
+        && filter.family_id.is_none_or(|family_id| platform.family.as_ref().is_some_and(|f| f.id == family_id))
+}
+
+#[async_trait]
+impl PlatformProvider for CachedPlatformProvider {
+    async fn find_by_id(&self, id: i64) -> Result<Option<Platform>, DomainError> {
+        match self.platforms() {
+            Some(platforms) => Ok(platforms.iter().find(|p| p.id == id).cloned()),
+            None => self.inner.find_by_id(id).await,
+        }
+    }
+
+    async fn list_platforms(&self, filter: &PlatformFilter, limit: i32, offset: i32) -> Result<Page<Platform>, DomainError> {
+        let Some(platforms) = self.platforms() else {
+            return self.inner.list_platforms(filter, limit, offset).await;
+        };
+
+        let matching: Vec<&Platform> = platforms.iter().filter(|p| matches(p, filter)).collect();
+        let content = matching
+            .iter()
+            .skip(offset.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .map(|p| (*p).clone())
+            .collect();
+        let page = if limit > 0 { offset / limit } else { 0 };
+
+        Ok(Page::new(content, page, limit, matching.len() as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use crate::domain::platform::{PlatformFamily, PlatformType};
+
+    struct FakeProvider {
+        failing: AtomicBool,
+    }
+
+    fn platform(id: i64, platform_type: PlatformType, family_id: Option<i64>) -> Platform {
+        Platform {
+            id,
+            name: format!("Platform {}", id),
+            generation: Some(8),
+            platform_type,
+            abbreviation: None,
+            family: family_id.map(|id| PlatformFamily { id, name: format!("Family {}", id) }),
+            logo: None,
+            versions: vec![],
+        }
+    }
+
+    #[async_trait]
+    impl PlatformProvider for FakeProvider {
+        async fn find_by_id(&self, _id: i64) -> Result<Option<Platform>, DomainError> {
+            unreachable!("served from the snapshot")
+        }
+
+        async fn list_platforms(&self, _filter: &PlatformFilter, _limit: i32, _offset: i32) -> Result<Page<Platform>, DomainError> {
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(DomainError::Upstream("IGDB is down".to_string()));
+            }
+            let platforms = vec![
+                platform(1, PlatformType::Console, Some(1)),
+                platform(2, PlatformType::PortableConsole, Some(1)),
+                platform(3, PlatformType::Computer, None),
+            ];
+            Ok(Page::new(platforms, 0, 500, 3))
+        }
+    }
+
+    #[tokio::test]
+    async fn filters_and_pages_the_snapshot() {
+        let cache = CachedPlatformProvider::new(Arc::new(FakeProvider { failing: AtomicBool::new(false) }));
+        cache.refresh().await.unwrap();
+
+        let filter = PlatformFilter { family_id: Some(1), ..PlatformFilter::default() };
+        let page = cache.list_platforms(&filter, 1, 1).await.unwrap();
+
+        assert_eq!(page.content.iter().map(|p| p.id).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(page.total_elements, 2);
+        assert_eq!(cache.find_by_id(3).await.unwrap().map(|p| p.id), Some(3));
+    }
+
+    #[tokio::test]
+    async fn keeps_last_good_snapshot_when_refresh_fails() {
+        let inner = Arc::new(FakeProvider { failing: AtomicBool::new(false) });
+        let cache = CachedPlatformProvider::new(inner.clone());
+        cache.refresh().await.unwrap();
+
+        inner.failing.store(true, Ordering::SeqCst);
+        assert!(cache.refresh().await.is_err());
+
+        let status = cache.status();
+        assert_eq!(status.platforms, 3);
+        assert!(status.snapshot_age_seconds.is_some());
+        assert_eq!(status.last_refresh_error.as_deref(), Some("Upstream error: IGDB is down"));
+        assert_eq!(cache.find_by_id(1).await.unwrap().map(|p| p.id), Some(1));
+    }
+}
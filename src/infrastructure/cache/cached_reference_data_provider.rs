@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use crate::application::ports::output::reference_data_provider::ReferenceDataProvider;
+use crate::domain::error::DomainError;
+use crate::domain::reference_data::{ReferenceDataKind, ReferenceItem};
+
+struct Entry {
+    items: Vec<ReferenceItem>,
+    loaded_at: Instant,
+}
+
+/// `ReferenceDataProvider` decorator that keeps each list in memory for `ttl`.
+/// Lists are loaded on first use; when a reload fails the expired list is served instead.
+pub struct CachedReferenceDataProvider {
+    inner: Arc<dyn ReferenceDataProvider>,
+    ttl: Duration,
+    // Held across the reload so concurrent requests for an expired list trigger one upstream call.
+    entries: Mutex<HashMap<ReferenceDataKind, Entry>>,
+}
+
+impl CachedReferenceDataProvider {
+    pub fn new(inner: Arc<dyn ReferenceDataProvider>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+}
+
+#[async_trait]
+impl ReferenceDataProvider for CachedReferenceDataProvider {
+    async fn list(&self, kind: ReferenceDataKind) -> Result<Vec<ReferenceItem>, DomainError> {
+        let mut entries = self.entries.lock().await;
+
+        if let Some(entry) = entries.get(&kind) {
+            if entry.loaded_at.elapsed() < self.ttl {
+                return Ok(entry.items.clone());
+            }
+        }
+
+        match self.inner.list(kind).await {
+            Ok(items) => {
+                entries.insert(kind, Entry { items: items.clone(), loaded_at: Instant::now() });
+                Ok(items)
+            }
+            Err(e) => match entries.get(&kind) {
+                Some(stale) => {
+                    tracing::warn!("Reloading {:?} reference data failed, serving expired list: {}", kind, e);
+                    Ok(stale.items.clone())
+                }
+                None => Err(e),
+            },
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use crate::domain::game::Game;
+use crate::domain::game_suggestion::GameSuggestion;
+
+struct Entry {
+    suggestion: GameSuggestion,
+    normalized: String,
+}
+
+#[derive(Default)]
+struct Index {
+    entries: HashMap<i64, Entry>,
+    trigrams: HashMap<String, HashSet<i64>>,
+}
+
+/// In-memory trigram index over the names of the games the application has already seen.
+/// Queries of three or more characters only look at games sharing all of their trigrams;
+/// shorter ones scan every name.
+#[derive(Default)]
+pub struct GameNameIndex {
+    index: RwLock<Index>,
+}
+
+impl GameNameIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.read().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn insert_games(&self, games: &[Game]) {
+        let suggestions: Vec<GameSuggestion> = games.iter().map(GameSuggestion::from).collect();
+        self.insert(&suggestions);
+    }
+
+    /// Adds or replaces the given games.
+    pub fn insert(&self, suggestions: &[GameSuggestion]) {
+        let mut index = self.index.write().unwrap();
+
+        for suggestion in suggestions {
+            let normalized = normalize(&suggestion.name);
+
+            if let Some(previous) = index.entries.remove(&suggestion.id) {
+                for trigram in trigrams(&previous.normalized) {
+                    if let Some(ids) = index.trigrams.get_mut(&trigram) {
+                        ids.remove(&suggestion.id);
+                        if ids.is_empty() {
+                            index.trigrams.remove(&trigram);
+                        }
+                    }
+                }
+            }
+            for trigram in trigrams(&normalized) {
+                index.trigrams.entry(trigram).or_default().insert(suggestion.id);
+            }
+            index.entries.insert(suggestion.id, Entry { suggestion: suggestion.clone(), normalized });
+        }
+    }
+
+    /// Games whose name contains `query` (case and punctuation insensitive). Names starting
+    /// with the query come first, then names with a word starting with it, then shorter names.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<GameSuggestion> {
+        let query = normalize(query);
+        if query.is_empty() || limit == 0 {
+            return vec![];
+        }
+        let index = self.index.read().unwrap();
+
+        let query_trigrams = trigrams(&query);
+        let candidates: Vec<&Entry> = if query_trigrams.is_empty() {
+            index.entries.values().collect()
+        } else {
+            let mut postings: Vec<&HashSet<i64>> = Vec::with_capacity(query_trigrams.len());
+            for trigram in &query_trigrams {
+                match index.trigrams.get(trigram) {
+                    Some(ids) => postings.push(ids),
+                    None => return vec![],
+                }
+            }
+            postings.sort_by_key(|ids| ids.len());
+            postings[0]
+                .iter()
+                .filter(|id| postings[1..].iter().all(|ids| ids.contains(id)))
+                .filter_map(|id| index.entries.get(id))
+                .collect()
+        };
+
+        let word_prefix = format!(" {}", query);
+        let mut matches: Vec<(u8, &Entry)> = candidates
+            .into_iter()
+            .filter_map(|entry| {
+                let rank = if entry.normalized.starts_with(&query) {
+                    0
+                } else if entry.normalized.contains(&word_prefix) {
+                    1
+                } else if entry.normalized.contains(&query) {
+                    2
+                } else {
+                    return None;
+                };
+                Some((rank, entry))
+            })
+            .collect();
+
+        matches.sort_by(|(rank_a, a), (rank_b, b)| {
+            rank_a.cmp(rank_b)
+                .then(a.normalized.len().cmp(&b.normalized.len()))
+                .then(a.normalized.cmp(&b.normalized))
+                .then(a.suggestion.id.cmp(&b.suggestion.id))
+        });
+        matches.into_iter().take(limit).map(|(_, entry)| entry.suggestion.clone()).collect()
+    }
+}
+
+// Lowercase words separated by single spaces; punctuation counts as a separator.
+pub fn normalize(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn trigrams(normalized: &str) -> HashSet<String> {
+    let chars: Vec<char> = normalized.chars().collect();
+    chars.windows(3).map(|window| window.iter().collect()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestion(id: i64, name: &str) -> GameSuggestion {
+        GameSuggestion { id, name: name.to_string(), release_year: None, cover: None }
+    }
+
+    fn names(results: Vec<GameSuggestion>) -> Vec<String> {
+        results.into_iter().map(|s| s.name).collect()
+    }
+
+    #[test]
+    fn ranks_prefix_then_word_prefix_then_substring() {
+        let index = GameNameIndex::new();
+        index.insert(&[
+            suggestion(1, "The Legend of Zelda: Breath of the Wild"),
+            suggestion(2, "Zelda II: The Adventure of Link"),
+            suggestion(3, "Hyrule Warriors: Zelda Edition"),
+            suggestion(4, "Super Mario Odyssey"),
+        ]);
+
+        assert_eq!(
+            names(index.search("zel", 10)),
+            vec!["Zelda II: The Adventure of Link", "Hyrule Warriors: Zelda Edition", "The Legend of Zelda: Breath of the Wild"]
+        );
+        assert_eq!(names(index.search("legend of z", 10)), vec!["The Legend of Zelda: Breath of the Wild"]);
+        assert_eq!(names(index.search("ss", 10)), vec!["Super Mario Odyssey"]);
+        assert!(index.search("metroid", 10).is_empty());
+    }
+
+    #[test]
+    fn reinserting_a_game_replaces_its_name() {
+        let index = GameNameIndex::new();
+        index.insert(&[suggestion(1, "Untitled Goose")]);
+        index.insert(&[suggestion(1, "Untitled Goose Game")]);
+
+        assert_eq!(index.len(), 1);
+        assert_eq!(names(index.search("goose game", 10)), vec!["Untitled Goose Game"]);
+        index.insert(&[suggestion(1, "Goose")]);
+        assert!(index.search("untitled", 10).is_empty());
+    }
+}
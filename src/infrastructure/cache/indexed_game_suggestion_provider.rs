@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use futures::future::{BoxFuture, FutureExt, Shared};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use crate::application::ports::output::game_suggestion_provider::GameSuggestionProvider;
+use crate::domain::error::DomainError;
+use crate::domain::game_suggestion::GameSuggestion;
+use crate::infrastructure::cache::game_name_index::{normalize, GameNameIndex};
+
+// One-letter queries match too much of the catalog to be worth an upstream search.
+const MIN_REMOTE_QUERY_CHARS: usize = 2;
+
+type RemoteLookup = Shared<BoxFuture<'static, Result<Vec<GameSuggestion>, DomainError>>>;
+
+/// `GameSuggestionProvider` decorator that answers from the local name index and only asks
+/// `inner` when the index has fewer than `min_local_results` matches. Identical upstream
+/// lookups that overlap in time share a single request, and their results are indexed.
+pub struct IndexedGameSuggestionProvider {
+    inner: Arc<dyn GameSuggestionProvider>,
+    index: Arc<GameNameIndex>,
+    min_local_results: usize,
+    in_flight: Arc<Mutex<HashMap<(String, usize), RemoteLookup>>>,
+}
+
+impl IndexedGameSuggestionProvider {
+    pub fn new(inner: Arc<dyn GameSuggestionProvider>, index: Arc<GameNameIndex>, min_local_results: usize) -> Self {
+        Self {
+            inner,
+            index,
+            min_local_results,
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    async fn remote(&self, query: &str, limit: usize) -> Result<Vec<GameSuggestion>, DomainError> {
+        let key = (normalize(query), limit);
+
+        let lookup = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            match in_flight.get(&key) {
+                Some(lookup) => lookup.clone(),
+                None => {
+                    let inner = self.inner.clone();
+                    let index = self.index.clone();
+                    let registry = self.in_flight.clone();
+                    let owned_key = key.clone();
+                    let query = query.to_string();
+                    let lookup = async move {
+                        let result = inner.suggest(&query, limit).await;
+                        if let Ok(suggestions) = &result {
+                            index.insert(suggestions);
+                        }
+                        registry.lock().unwrap().remove(&owned_key);
+                        result
+                    }
+                    .boxed()
+                    .shared();
+                    in_flight.insert(key, lookup.clone());
+                    lookup
+                }
+            }
+        };
+
+        lookup.await
+    }
+}
+
+#[async_trait]
+impl GameSuggestionProvider for IndexedGameSuggestionProvider {
+    async fn suggest(&self, query: &str, limit: usize) -> Result<Vec<GameSuggestion>, DomainError> {
+        let local = self.index.search(query, limit);
+        if local.len() >= self.min_local_results.min(limit) || normalize(query).chars().count() < MIN_REMOTE_QUERY_CHARS {
+            return Ok(local);
+        }
+
+        let remote = match self.remote(query, limit).await {
+            Ok(remote) => remote,
+            Err(e) => {
+                tracing::warn!("Autocomplete lookup for '{}' failed, serving local matches only: {}", query, e);
+                return Ok(local);
+            }
+        };
+
+        // Local matches keep their ranking; upstream ones fill the remaining slots.
+        let mut seen: HashSet<i64> = local.iter().map(|suggestion| suggestion.id).collect();
+        let mut merged = local;
+        merged.extend(remote.into_iter().filter(|suggestion| seen.insert(suggestion.id)));
+        merged.truncate(limit);
+        Ok(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    struct SlowProvider {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl GameSuggestionProvider for SlowProvider {
+        async fn suggest(&self, _query: &str, _limit: usize) -> Result<Vec<GameSuggestion>, DomainError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok(vec![GameSuggestion { id: 7, name: "Hades".to_string(), release_year: Some(2020), cover: None }])
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_misses_share_one_upstream_call_and_fill_the_index() {
+        let inner = Arc::new(SlowProvider { calls: AtomicUsize::new(0) });
+        let provider = IndexedGameSuggestionProvider::new(inner.clone(), Arc::new(GameNameIndex::new()), 1);
+
+        let (a, b) = tokio::join!(provider.suggest("had", 5), provider.suggest("HAD", 5));
+        assert_eq!(a.unwrap().len(), 1);
+        assert_eq!(b.unwrap().len(), 1);
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+
+        // Now answered from the index.
+        assert_eq!(provider.suggest("hade", 5).await.unwrap()[0].id, 7);
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+    }
+}
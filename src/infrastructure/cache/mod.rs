@@ -0,0 +1,4 @@
+pub mod cached_platform_provider;
+pub mod cached_reference_data_provider;
+pub mod game_name_index;
+pub mod indexed_game_suggestion_provider;
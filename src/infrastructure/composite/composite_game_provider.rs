@@ -0,0 +1,372 @@
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::application::ports::output::game_provider::GameProvider;
+use crate::domain::error::DomainError;
+use crate::domain::game::{Game, TimeToBeat};
+use crate::domain::game_filter::GameFilter;
+use crate::domain::page::Page;
+
+/// A `GameProvider` taking part in a `CompositeGameProvider`, under the name recorded in
+/// `Game::sources`.
+pub struct GameSource {
+    pub name: String,
+    pub provider: Arc<dyn GameProvider>,
+}
+
+impl GameSource {
+    pub fn new(name: &str, provider: Arc<dyn GameProvider>) -> Self {
+        Self { name: name.to_string(), provider }
+    }
+}
+
+/// `GameProvider` that asks its sources in priority order. The first source that answers
+/// serves the game. A failing source is skipped, and an error is returned only when the
+/// sources after it could not answer in its place.
+///
+/// Only single-game lookups ask the following sources to fill the main fields (summary,
+/// release date, cover, platforms, genres) the first one left empty; doing it for lists would
+/// add an upstream round trip to every search.
+pub struct CompositeGameProvider {
+    sources: Vec<GameSource>,
+}
+
+impl CompositeGameProvider {
+    pub fn new(sources: Vec<GameSource>) -> Self {
+        assert!(!sources.is_empty(), "CompositeGameProvider needs at least one source");
+        Self { sources }
+    }
+
+    // Runs `lookup` against each source until one returns something. Errors win over
+    // "not found", since the failing source might have had the answer.
+    async fn first_answer<'a, T>(
+        &'a self,
+        operation: &str,
+        lookup: impl Fn(&'a dyn GameProvider) -> BoxFuture<'a, Result<Option<T>, DomainError>>,
+    ) -> Result<Option<(&'a str, T)>, DomainError> {
+        let mut first_error = None;
+        for source in &self.sources {
+            match lookup(source.provider.as_ref()).await {
+                Ok(Some(answer)) => {
+                    if first_error.is_some() {
+                        tracing::info!("{} served by fallback source '{}'", operation, source.name);
+                    }
+                    return Ok(Some((&source.name, answer)));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!("{} failed on source '{}': {}", operation, source.name, e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        first_error.map_or(Ok(None), Err)
+    }
+
+    // Asks every source that hasn't contributed to the game yet, while it still has empty
+    // fields. Failures only mean the gaps stay.
+    async fn fill_gaps(&self, game: &mut Game) {
+        for source in &self.sources {
+            if !has_gaps(game) {
+                return;
+            }
+            if game.sources.contains(&source.name) {
+                continue;
+            }
+
+            match source.provider.find_by_external_id(game.id).await {
+                Ok(Some(other)) => {
+                    if fill_missing(game, other) {
+                        game.sources.push(source.name.clone());
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Could not complete game {} from source '{}': {}", game.id, source.name, e),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl GameProvider for CompositeGameProvider {
+    async fn find_by_external_id(&self, external_id: i64) -> Result<Option<Game>, DomainError> {
+        let answer = self
+            .first_answer("Game lookup", |provider| provider.find_by_external_id(external_id))
+            .await?;
+
+        let Some((source, game)) = answer else {
+            return Ok(None);
+        };
+        let mut game = served_by(game, source);
+        self.fill_gaps(&mut game).await;
+        Ok(Some(game))
+    }
+
+    async fn find_multiple_by_external_ids(&self, external_ids: &[i64]) -> Result<Vec<Game>, DomainError> {
+        let mut found: HashMap<i64, Game> = HashMap::new();
+        let mut first_error = None;
+
+        // Each source is asked for the ids nobody has returned yet.
+        for source in &self.sources {
+            let missing: Vec<i64> = external_ids.iter().copied().filter(|id| !found.contains_key(id)).collect();
+            if missing.is_empty() {
+                break;
+            }
+            match source.provider.find_multiple_by_external_ids(&missing).await {
+                Ok(games) => {
+                    found.extend(games.into_iter().map(|game| (game.id, served_by(game, &source.name))));
+                }
+                Err(e) => {
+                    tracing::warn!("Batch lookup of {} games failed on source '{}': {}", missing.len(), source.name, e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        // Ids nobody returned might be known to the failing source. Failing the whole batch
+        // lets a caching layer above serve its stale copies instead of reporting them missing.
+        if let Some(e) = first_error {
+            if external_ids.iter().any(|id| !found.contains_key(id)) {
+                return Err(e);
+            }
+        }
+
+        Ok(external_ids.iter().filter_map(|id| found.remove(id)).collect())
+    }
+
+    async fn search_by_name(&self, name: &str) -> Result<Vec<Game>, DomainError> {
+        let answer = self
+            .first_answer("Game search", |provider| Box::pin(async move {
+                let games = provider.search_by_name(name).await?;
+                Ok((!games.is_empty()).then_some(games))
+            }))
+            .await?;
+
+        let Some((source, games)) = answer else {
+            return Ok(vec![]);
+        };
+        Ok(games.into_iter().map(|game| served_by(game, source)).collect())
+    }
+
+    async fn find_similar_games(&self, external_id: i64) -> Result<Option<Vec<Game>>, DomainError> {
+        let answer = self
+            .first_answer("Similar games lookup", |provider| provider.find_similar_games(external_id))
+            .await?;
+
+        let Some((source, games)) = answer else {
+            return Ok(None);
+        };
+        Ok(Some(games.into_iter().map(|game| served_by(game, source)).collect()))
+    }
+
+    async fn filter_games(&self, filter: &GameFilter, limit: i32, offset: i32) -> Result<Page<Game>, DomainError> {
+        let answer = self
+            .first_answer("Game filter", |provider| Box::pin(async move {
+                provider.filter_games(filter, limit, offset).await.map(Some)
+            }))
+            .await?;
+
+        // Every source answers a filter with a page, so a missing answer means no sources.
+        let (source, page) = answer.ok_or_else(|| DomainError::Internal("No game source answered".to_string()))?;
+        let Page { content, page, size, total_elements, .. } = page;
+        let content = content.into_iter().map(|game| served_by(game, source)).collect();
+        Ok(Page::new(content, page, size, total_elements))
+    }
+
+    // Each source is asked for the games the previous ones had no data for. Missing data is
+    // common, so a failing source only matters when no source answered at all.
+    async fn find_time_to_beat(&self, external_ids: &[i64]) -> Result<HashMap<i64, TimeToBeat>, DomainError> {
+        let mut found = HashMap::new();
+        let mut first_error = None;
+        let mut answered = false;
+
+        for source in &self.sources {
+            let missing: Vec<i64> = external_ids.iter().copied().filter(|id| !found.contains_key(id)).collect();
+            if missing.is_empty() {
+                break;
+            }
+            match source.provider.find_time_to_beat(&missing).await {
+                Ok(time_to_beat) => {
+                    answered = true;
+                    found.extend(time_to_beat);
+                }
+                Err(e) => {
+                    tracing::warn!("Time-to-beat lookup of {} games failed on source '{}': {}", missing.len(), source.name, e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match (answered, first_error) {
+            (false, Some(e)) => Err(e),
+            _ => Ok(found),
+        }
+    }
+}
+
+fn served_by(mut game: Game, source: &str) -> Game {
+    game.sources = vec![source.to_string()];
+    game
+}
+
+trait Field {
+    fn is_missing(&self) -> bool;
+}
+
+impl<T> Field for Option<T> {
+    fn is_missing(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<T> Field for Vec<T> {
+    fn is_missing(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+fn fill<T: Field>(target: &mut T, value: T) -> bool {
+    if target.is_missing() && !value.is_missing() {
+        *target = value;
+        true
+    } else {
+        false
+    }
+}
+
+// Fields worth a call to another source: the ones lists and the detail header show. Nearly
+// every game lacks some other optional field, so checking all of them would put the
+// secondary source on the path of every lookup.
+fn has_gaps(game: &Game) -> bool {
+    game.summary.is_missing() || game.release_date.is_missing() || game.cover.is_missing()
+        || game.platforms.is_missing() || game.genres.is_missing()
+}
+
+// Copies into `game` the fields checked by `has_gaps` that it lacks and `other` has.
+// Returns whether anything was copied.
+fn fill_missing(game: &mut Game, other: Game) -> bool {
+    let Game { summary, release_date, cover, platforms, genres, .. } = other;
+
+    [
+        fill(&mut game.summary, summary),
+        fill(&mut game.release_date, release_date),
+        fill(&mut game.cover, cover),
+        fill(&mut game.platforms, platforms),
+        fill(&mut game.genres, genres),
+    ]
+    .contains(&true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct FixedProvider {
+        games: Vec<Game>,
+        fail: bool,
+        lookups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl GameProvider for FixedProvider {
+        async fn find_by_external_id(&self, external_id: i64) -> Result<Option<Game>, DomainError> {
+            self.lookups.fetch_add(1, Ordering::Relaxed);
+            Ok(self.find_multiple_by_external_ids(&[external_id]).await?.pop())
+        }
+
+        async fn find_multiple_by_external_ids(&self, external_ids: &[i64]) -> Result<Vec<Game>, DomainError> {
+            if self.fail {
+                return Err(DomainError::Upstream("down".to_string()));
+            }
+            Ok(self.games.iter().filter(|game| external_ids.contains(&game.id)).cloned().collect())
+        }
+
+        async fn search_by_name(&self, _name: &str) -> Result<Vec<Game>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn find_similar_games(&self, _external_id: i64) -> Result<Option<Vec<Game>>, DomainError> {
+            Ok(None)
+        }
+
+        async fn filter_games(&self, _filter: &GameFilter, _limit: i32, _offset: i32) -> Result<Page<Game>, DomainError> {
+            Err(DomainError::Upstream("filter not supported".to_string()))
+        }
+    }
+
+    fn game(id: i64, summary: Option<&str>, genres: &[&str]) -> Game {
+        let json = serde_json::json!({
+            "id": id, "name": format!("Game {}", id), "summary": summary, "storyline": null,
+            "release_date": null, "rating": null, "cover": null, "platforms": [],
+            "genres": genres, "videos": [], "screenshots": [], "artworks": [],
+        });
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn source(name: &str, games: Vec<Game>, fail: bool) -> GameSource {
+        GameSource::new(name, Arc::new(FixedProvider { games, fail, lookups: AtomicUsize::new(0) }))
+    }
+
+    #[tokio::test]
+    async fn primary_serves_and_secondary_fills_only_missing_fields() {
+        let provider = CompositeGameProvider::new(vec![
+            source("igdb", vec![game(1, Some("From IGDB"), &[])], false),
+            source("metadata", vec![game(1, Some("From metadata"), &["Puzzle"]), game(2, None, &["RPG"])], false),
+        ]);
+
+        let game = provider.find_by_external_id(1).await.unwrap().unwrap();
+        assert_eq!(game.summary.as_deref(), Some("From IGDB"));
+        assert_eq!(game.genres, vec!["Puzzle"]);
+        assert_eq!(game.sources, vec!["igdb", "metadata"]);
+
+        // Batches are served as found, without asking other sources to fill gaps.
+        let games = provider.find_multiple_by_external_ids(&[2, 1, 3]).await.unwrap();
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].sources, vec!["metadata"]);
+        assert!(games[1].genres.is_empty());
+        assert_eq!(games[1].sources, vec!["igdb"]);
+    }
+
+    #[tokio::test]
+    async fn falls_back_when_the_primary_fails_and_errors_only_if_every_source_does() {
+        let provider = CompositeGameProvider::new(vec![
+            source("igdb", vec![], true),
+            source("metadata", vec![game(1, None, &[])], false),
+        ]);
+        let found = provider.find_by_external_id(1).await.unwrap().unwrap();
+        assert_eq!(found.sources, vec!["metadata"]);
+        // The failing primary might know the game, so "not found" is not trusted.
+        assert!(provider.find_by_external_id(2).await.is_err());
+
+        // A batch the fallback only partly answers fails, so stale copies can be served instead.
+        assert!(provider.find_multiple_by_external_ids(&[1, 2]).await.is_err());
+        let games = provider.find_multiple_by_external_ids(&[1]).await.unwrap();
+        assert_eq!(games[0].sources, vec!["metadata"]);
+
+        let all_down = CompositeGameProvider::new(vec![source("igdb", vec![], true), source("metadata", vec![], true)]);
+        assert!(all_down.find_multiple_by_external_ids(&[1]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn games_complete_in_the_main_fields_do_not_ask_other_sources() {
+        let mut complete = game(1, Some("From IGDB"), &["Puzzle"]);
+        complete.release_date = chrono::NaiveDate::from_ymd_opt(2019, 5, 28);
+        complete.platforms = vec!["PC".to_string()];
+        complete.cover = Some(serde_json::from_value(serde_json::json!({
+            "id": 10, "image_id": "co1", "width": null, "height": null,
+            "alpha_channel": false, "animated": false, "checksum": null, "artwork_type": null,
+        })).unwrap());
+        let secondary = Arc::new(FixedProvider { games: vec![game(1, None, &[])], fail: false, lookups: AtomicUsize::new(0) });
+        let provider = CompositeGameProvider::new(vec![
+            source("igdb", vec![complete], false),
+            GameSource::new("metadata", secondary.clone()),
+        ]);
+
+        // Storyline, videos, screenshots and the like are still empty.
+        let found = provider.find_by_external_id(1).await.unwrap().unwrap();
+        assert!(found.storyline.is_none() && found.videos.is_empty());
+        assert_eq!(found.sources, vec!["igdb"]);
+        assert_eq!(secondary.lookups.load(Ordering::Relaxed), 0);
+    }
+}
@@ -0,0 +1,81 @@
+use reqwest::{Certificate, Client, Proxy};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Settings shared by every outbound HTTP client (IGDB, Twitch, the metadata API).
+#[derive(Debug, Clone)]
+pub struct HttpClientConfig {
+    /// Time to open the TCP connection and finish the TLS handshake.
+    pub connect_timeout: Duration,
+    /// Time for a single request, from sending it to reading the whole body.
+    pub request_timeout: Duration,
+    /// Time for a whole call, including rate limiting, token refreshes and retries.
+    pub overall_timeout: Duration,
+    /// Proxy for all outbound requests. Without one, the standard `HTTP_PROXY`/`HTTPS_PROXY`
+    /// variables still apply.
+    pub proxy_url: Option<String>,
+    /// PEM file with extra root certificates, e.g. for a TLS-intercepting proxy.
+    pub ca_bundle_path: Option<PathBuf>,
+    pub pool_max_idle_per_host: usize,
+    pub pool_idle_timeout: Duration,
+    pub user_agent: String,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(5),
+            request_timeout: Duration::from_secs(10),
+            overall_timeout: Duration::from_secs(30),
+            proxy_url: None,
+            ca_bundle_path: None,
+            pool_max_idle_per_host: 8,
+            pool_idle_timeout: Duration::from_secs(90),
+            user_agent: concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")).to_string(),
+        }
+    }
+}
+
+impl HttpClientConfig {
+    /// Fails on an invalid proxy URL or an unreadable CA bundle.
+    pub fn build_client(&self) -> Result<Client, String> {
+        let mut builder = Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.request_timeout)
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .user_agent(self.user_agent.as_str());
+
+        if let Some(url) = &self.proxy_url {
+            let proxy = Proxy::all(url).map_err(|e| format!("Invalid proxy URL '{}': {}", url, e))?;
+            builder = builder.proxy(proxy);
+        }
+
+        if let Some(path) = &self.ca_bundle_path {
+            let pem = std::fs::read(path).map_err(|e| format!("Could not read CA bundle {}: {}", path.display(), e))?;
+            let certificates = Certificate::from_pem_bundle(&pem)
+                .map_err(|e| format!("Invalid CA bundle {}: {}", path.display(), e))?;
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        builder.build().map_err(|e| format!("Could not build the HTTP client: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_proxy_and_missing_ca_bundle_are_reported() {
+        assert!(HttpClientConfig::default().build_client().is_ok());
+
+        let config = HttpClientConfig { proxy_url: Some("not a url".to_string()), ..HttpClientConfig::default() };
+        assert!(config.build_client().unwrap_err().contains("Invalid proxy URL"));
+
+        let config = HttpClientConfig { ca_bundle_path: Some(PathBuf::from("/nonexistent/ca.pem")), ..HttpClientConfig::default() };
+        assert!(config.build_client().unwrap_err().contains("Could not read CA bundle"));
+    }
+}
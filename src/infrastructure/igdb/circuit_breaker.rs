@@ -0,0 +1,201 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// When the IGDB circuit opens and how it recovers.
+#[derive(Debug, Clone, Copy)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failed calls that open the circuit.
+    pub failure_threshold: u32,
+    /// How long calls are rejected before probing IGDB again.
+    pub open_duration: Duration,
+    /// Calls let through at once while half-open.
+    pub half_open_max_probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+            half_open_max_probes: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+/// Snapshot of the breaker for the health endpoint.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitBreakerStatus {
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    /// Seconds until a probe is allowed, while open.
+    pub retry_in_seconds: Option<u64>,
+    pub rejected_calls: u64,
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    Closed { consecutive_failures: u32 },
+    Open { since: Instant, consecutive_failures: u32 },
+    HalfOpen { probes_in_flight: u32 },
+}
+
+struct Inner {
+    state: State,
+    rejected_calls: u64,
+}
+
+/// Stops calling IGDB after repeated failures so requests fail fast instead of waiting
+/// on an outage. After `open_duration` a few probe calls are let through: the first
+/// success closes the circuit again, a failure reopens it.
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config: CircuitBreakerConfig {
+                failure_threshold: config.failure_threshold.max(1),
+                half_open_max_probes: config.half_open_max_probes.max(1),
+                ..config
+            },
+            inner: Mutex::new(Inner { state: State::Closed { consecutive_failures: 0 }, rejected_calls: 0 }),
+        }
+    }
+
+    /// Lets a call through, or returns `None` while the circuit is open. The outcome must
+    /// be reported on the returned permit.
+    pub fn try_acquire(&self) -> Option<CircuitPermit<'_>> {
+        let mut inner = self.inner.lock().unwrap();
+
+        if let State::Open { since, .. } = inner.state {
+            if since.elapsed() >= self.config.open_duration {
+                inner.state = State::HalfOpen { probes_in_flight: 0 };
+            }
+        }
+
+        let probe = match &mut inner.state {
+            State::Closed { .. } => false,
+            State::HalfOpen { probes_in_flight } if *probes_in_flight < self.config.half_open_max_probes => {
+                *probes_in_flight += 1;
+                true
+            }
+            State::Open { .. } | State::HalfOpen { .. } => {
+                inner.rejected_calls += 1;
+                return None;
+            }
+        };
+
+        Some(CircuitPermit { breaker: self, probe, reported: false })
+    }
+
+    pub fn status(&self) -> CircuitBreakerStatus {
+        let inner = self.inner.lock().unwrap();
+        let (state, consecutive_failures, retry_in_seconds) = match inner.state {
+            State::Closed { consecutive_failures } => (CircuitState::Closed, consecutive_failures, None),
+            State::Open { since, consecutive_failures } => (
+                CircuitState::Open,
+                consecutive_failures,
+                Some(self.config.open_duration.saturating_sub(since.elapsed()).as_secs()),
+            ),
+            State::HalfOpen { .. } => (CircuitState::HalfOpen, 0, None),
+        };
+        CircuitBreakerStatus { state, consecutive_failures, retry_in_seconds, rejected_calls: inner.rejected_calls }
+    }
+
+    fn record_success(&self) {
+        self.inner.lock().unwrap().state = State::Closed { consecutive_failures: 0 };
+    }
+
+    fn record_failure(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.state = match inner.state {
+            State::Closed { consecutive_failures } if consecutive_failures + 1 < self.config.failure_threshold => {
+                State::Closed { consecutive_failures: consecutive_failures + 1 }
+            }
+            State::Closed { consecutive_failures } => {
+                tracing::warn!("IGDB circuit opened after {} consecutive failures", consecutive_failures + 1);
+                State::Open { since: Instant::now(), consecutive_failures: consecutive_failures + 1 }
+            }
+            State::HalfOpen { .. } => {
+                tracing::warn!("IGDB probe failed, circuit reopened");
+                State::Open { since: Instant::now(), consecutive_failures: 1 }
+            }
+            // A call let through before the circuit opened.
+            State::Open { since, consecutive_failures } => State::Open { since, consecutive_failures: consecutive_failures + 1 },
+        };
+    }
+}
+
+/// A call allowed by the breaker. Dropping it unreported (e.g. a cancelled request)
+/// frees its probe slot without counting as a success or a failure.
+pub struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    reported: bool,
+}
+
+impl CircuitPermit<'_> {
+    pub fn success(mut self) {
+        self.reported = true;
+        self.breaker.record_success();
+    }
+
+    pub fn failure(mut self) {
+        self.reported = true;
+        self.breaker.record_failure();
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if self.probe && !self.reported {
+            if let State::HalfOpen { probes_in_flight } = &mut self.breaker.inner.lock().unwrap().state {
+                *probes_in_flight = probes_in_flight.saturating_sub(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_after_the_threshold_and_closes_after_a_successful_probe() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_duration: Duration::from_millis(20),
+            half_open_max_probes: 1,
+        });
+
+        breaker.try_acquire().unwrap().failure();
+        assert_eq!(breaker.status().state, CircuitState::Closed);
+        breaker.try_acquire().unwrap().failure();
+        assert_eq!(breaker.status().state, CircuitState::Open);
+        assert!(breaker.try_acquire().is_none());
+
+        std::thread::sleep(Duration::from_millis(25));
+        let probe = breaker.try_acquire().unwrap();
+        assert!(breaker.try_acquire().is_none(), "only one probe at a time");
+        probe.failure();
+        assert_eq!(breaker.status().state, CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(25));
+        // An abandoned probe frees its slot.
+        drop(breaker.try_acquire().unwrap());
+        breaker.try_acquire().unwrap().success();
+        assert_eq!(breaker.status().state, CircuitState::Closed);
+        assert_eq!(breaker.status().rejected_calls, 2);
+    }
+}
@@ -1,100 +1,236 @@
 use reqwest::{Client, StatusCode};
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use std::time::{SystemTime, Duration};
+use std::time::Duration;
 use serde::de::DeserializeOwned;
-use crate::infrastructure::igdb::dtos::TwitchTokenResponse;
+use crate::domain::error::DomainError;
+use crate::infrastructure::http::client_config::HttpClientConfig;
+use crate::infrastructure::igdb::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+use crate::infrastructure::igdb::fixtures::FixtureMode;
+use crate::infrastructure::igdb::multiquery::{MultiQuery, MultiQueryResponse, MultiQueryResult, MAX_SUB_QUERIES};
+use crate::infrastructure::igdb::rate_limiter::RateLimiter;
+use crate::infrastructure::igdb::request_coalescer::RequestCoalescer;
+use crate::infrastructure::igdb::retry::{self, RetryPolicy};
+use crate::infrastructure::igdb::token_provider::TwitchTokenProvider;
+
+// IGDB documents a limit of 4 requests per second and 8 open requests.
+const DEFAULT_REQUESTS_PER_SECOND: u32 = 4;
+const DEFAULT_MAX_IN_FLIGHT: usize = 8;
+
+// Why a live call failed. Only outages count against the circuit breaker: a request
+// IGDB rejects as malformed says nothing about its health.
+enum CallError {
+    Outage(DomainError),
+    Rejected(DomainError),
+}
 
 #[derive(Clone)]
 pub struct IgdbClient {
     client: Client,
+    overall_timeout: Duration,
     client_id: String,
-    client_secret: String,
     base_url: String,
-    auth_url: String,
-    token: Arc<RwLock<Option<String>>>,
-    token_expiry: Arc<RwLock<SystemTime>>,
+    token_provider: Arc<TwitchTokenProvider>,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
+    fixtures: FixtureMode,
+    circuit_breaker: Arc<CircuitBreaker>,
+    coalescer: Arc<RequestCoalescer>,
 }
 
 impl IgdbClient {
-    pub fn new(client_id: String, client_secret: String, base_url: String, auth_url: String) -> Self {
+    /// `client` is used for both IGDB and Twitch requests, so it carries the shared outbound
+    /// HTTP settings (timeouts, proxy, user agent).
+    pub fn new(client: Client, client_id: String, client_secret: String, base_url: String, auth_url: String) -> Self {
+        let token_provider = Arc::new(TwitchTokenProvider::new(client.clone(), client_id.clone(), client_secret, auth_url));
         Self {
-            client: Client::new(),
+            client,
+            overall_timeout: HttpClientConfig::default().overall_timeout,
             client_id,
-            client_secret,
             base_url,
-            auth_url,
-            token: Arc::new(RwLock::new(None)),
-            token_expiry: Arc::new(RwLock::new(SystemTime::UNIX_EPOCH)),
+            token_provider,
+            rate_limiter: Arc::new(RateLimiter::new(DEFAULT_REQUESTS_PER_SECOND, DEFAULT_MAX_IN_FLIGHT)),
+            retry_policy: RetryPolicy::default(),
+            fixtures: FixtureMode::Live,
+            circuit_breaker: Arc::new(CircuitBreaker::new(CircuitBreakerConfig::default())),
+            coalescer: Arc::new(RequestCoalescer::new()),
         }
     }
 
-    async fn get_token(&self) -> Result<String, String> {
-        let now = SystemTime::now();
-        let expiry = *self.token_expiry.read().await;
+    /// Limit for a whole call, including rate limiting, token refreshes and retries.
+    pub fn with_overall_timeout(mut self, timeout: Duration) -> Self {
+        self.overall_timeout = timeout;
+        self
+    }
 
-        if now < expiry {
-            if let Some(token) = self.token.read().await.as_ref() {
-                return Ok(token.clone());
-            }
-        }
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Arc::new(CircuitBreaker::new(config));
+        self
+    }
 
-        // Refresh token
-        let params = [
-            ("client_id", &self.client_id),
-            ("client_secret", &self.client_secret),
-            ("grant_type", &"client_credentials".to_string()),
-        ];
+    pub fn circuit_breaker(&self) -> Arc<CircuitBreaker> {
+        self.circuit_breaker.clone()
+    }
 
-        let response = self.client.post(&self.auth_url)
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+    pub fn token_provider(&self) -> Arc<TwitchTokenProvider> {
+        self.token_provider.clone()
+    }
 
-        if !response.status().is_success() {
-            return Err(format!("Failed to get Twitch token: {}", response.status()));
-        }
+    pub fn request_coalescer(&self) -> Arc<RequestCoalescer> {
+        self.coalescer.clone()
+    }
 
-        let token_response: TwitchTokenResponse = response.json().await.map_err(|e| e.to_string())?;
+    pub fn with_rate_limit(mut self, requests_per_second: u32, max_in_flight: usize) -> Self {
+        self.rate_limiter = Arc::new(RateLimiter::new(requests_per_second, max_in_flight));
+        self
+    }
 
-        let mut token_guard = self.token.write().await;
-        *token_guard = Some(token_response.access_token.clone());
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
 
-        let mut expiry_guard = self.token_expiry.write().await;
-        *expiry_guard = SystemTime::now() + Duration::from_secs(token_response.expires_in as u64 - 60); // Buffer of 60s
+    pub fn with_fixtures(mut self, fixtures: FixtureMode) -> Self {
+        self.fixtures = fixtures;
+        self
+    }
 
-        Ok(token_response.access_token)
+    // Generic post that returns T (can be Vec<Something> or just Something).
+    // Concurrent identical queries share a single upstream call.
+    pub async fn post<T: DeserializeOwned>(&self, endpoint: &str, body: String) -> Result<T, DomainError> {
+        let client = self.clone();
+        let owned_endpoint = endpoint.to_string();
+        let owned_body = body.clone();
+        let response_text = self.coalescer
+            .run(endpoint, &body, async move { client.fetch(&owned_endpoint, &owned_body).await.map(Arc::from) })
+            .await?;
+
+        // Try to deserialize
+        serde_json::from_str::<T>(&response_text)
+            .map_err(|e| DomainError::Upstream(format!("JSON Deserialization Error: {} for response: {}", e, response_text)))
     }
 
-    // Generic post that returns T (can be Vec<Something> or just Something)
-    pub async fn post<T: DeserializeOwned>(&self, endpoint: &str, body: String) -> Result<T, String> {
-        let token = self.get_token().await?;
-        let url = format!("{}/{}", self.base_url, endpoint);
+    // Runs several named queries in a single request against the `multiquery` endpoint
+    pub async fn multiquery(&self, query: &MultiQuery) -> Result<MultiQueryResponse, DomainError> {
+        if query.is_empty() {
+            return Ok(MultiQueryResponse::new(vec![]));
+        }
+        if query.len() > MAX_SUB_QUERIES {
+            return Err(DomainError::Internal(format!(
+                "IGDB multiquery supports at most {} sub-queries, got {}", MAX_SUB_QUERIES, query.len()
+            )));
+        }
 
-        let response = self.client.post(&url)
-            .header("Client-ID", &self.client_id)
-            .header("Authorization", format!("Bearer {}", token))
-            .body(body)
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
+        let results: Vec<MultiQueryResult> = self.post("multiquery", query.build()).await?;
+        Ok(MultiQueryResponse::new(results))
+    }
 
-        if response.status() == StatusCode::UNAUTHORIZED {
-            let mut expiry_guard = self.token_expiry.write().await;
-            *expiry_guard = SystemTime::UNIX_EPOCH;
-            return Err("Unauthorized access to IGDB. Token might be expired.".to_string());
+    async fn fetch(&self, endpoint: &str, body: &str) -> Result<String, DomainError> {
+        match &self.fixtures {
+            FixtureMode::Live => self.send_guarded(endpoint, body).await,
+            FixtureMode::Replay(store) => store.load(endpoint, body).await,
+            FixtureMode::Record(store) => {
+                let text = self.send_guarded(endpoint, body).await?;
+                if let Err(e) = store.save(endpoint, body, &text).await {
+                    tracing::warn!("Could not record IGDB fixture for '{}': {}", endpoint, e);
+                }
+                Ok(text)
+            }
         }
+    }
+
+    // Fails fast while the circuit is open; otherwise reports the outcome to the breaker.
+    async fn send_guarded(&self, endpoint: &str, body: &str) -> Result<String, DomainError> {
+        let permit = self.circuit_breaker.try_acquire().ok_or_else(|| {
+            DomainError::Upstream(format!("IGDB circuit breaker is open, not calling '{}'", endpoint))
+        })?;
 
-        if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(format!("IGDB API Error: {} - {}", endpoint, error_text));
+        let result = tokio::time::timeout(self.overall_timeout, self.send_with_retries(endpoint, body))
+            .await
+            .unwrap_or_else(|_| Err(CallError::Outage(DomainError::Upstream(format!(
+                "IGDB call to '{}' did not finish within {:?}", endpoint, self.overall_timeout
+            )))));
+
+        match result {
+            Ok(text) => {
+                permit.success();
+                Ok(text)
+            }
+            Err(CallError::Rejected(e)) => {
+                permit.success();
+                Err(e)
+            }
+            Err(CallError::Outage(e)) => {
+                permit.failure();
+                Err(e)
+            }
         }
+    }
 
-        let response_text = response.text().await.map_err(|e| e.to_string())?;
+    // Sends the request through the rate limiter, retrying transient failures with
+    // jittered backoff. A 401 refreshes the token and retries exactly once.
+    async fn send_with_retries(&self, endpoint: &str, body: &str) -> Result<String, CallError> {
+        let url = format!("{}/{}", self.base_url, endpoint);
+        let mut attempt = 0;
+        let mut token_refreshed = false;
+
+        loop {
+            let token = self.token_provider.get().await.map_err(CallError::Outage)?;
+            let permit = self.rate_limiter.acquire().await;
+
+            let result = self.client.post(&url)
+                .header("Client-ID", &self.client_id)
+                .header("Authorization", format!("Bearer {}", token))
+                .body(body.to_string())
+                .send()
+                .await;
+
+            let response = match result {
+                Ok(response) => response,
+                Err(e) if retry::is_retryable_error(&e) && attempt < self.retry_policy.max_retries => {
+                    drop(permit);
+                    let delay = self.retry_policy.backoff(attempt);
+                    tracing::warn!("IGDB request to '{}' failed ({}), retrying in {:?}", endpoint, e, delay);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                    continue;
+                }
+                Err(e) => return Err(CallError::Outage(DomainError::Upstream(e.to_string()))),
+            };
+
+            let status = response.status();
+
+            if status == StatusCode::UNAUTHORIZED {
+                drop(permit);
+                self.token_provider.invalidate(&token);
+                if !token_refreshed {
+                    tracing::warn!("IGDB rejected the access token, refreshing it and retrying");
+                    token_refreshed = true;
+                    continue;
+                }
+                return Err(CallError::Outage(DomainError::Upstream("Unauthorized access to IGDB. Token might be expired.".to_string())));
+            }
 
-        // Try to deserialize
-        serde_json::from_str::<T>(&response_text)
-            .map_err(|e| format!("JSON Deserialization Error: {} for response: {}", e, response_text))
+            if retry::is_retryable_status(status) && attempt < self.retry_policy.max_retries {
+                let retry_after = retry::parse_retry_after(response.headers());
+                drop(permit);
+                let delay = self.retry_policy.delay_for(attempt, retry_after);
+                tracing::warn!("IGDB returned {} for '{}', retrying in {:?}", status, endpoint, delay);
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            if !status.is_success() {
+                let error_text = response.text().await.unwrap_or_default();
+                return Err(if retry::is_retryable_status(status) || status.is_server_error() {
+                    CallError::Outage(DomainError::Upstream(format!("IGDB API Error: {} - {}", endpoint, error_text)))
+                } else {
+                    // A query IGDB refuses is our bug, not an outage.
+                    CallError::Rejected(DomainError::Internal(format!("IGDB rejected '{}' with {}: {}", endpoint, status, error_text)))
+                });
+            }
+
+            return response.text().await.map_err(|e| CallError::Outage(DomainError::Upstream(e.to_string())));
+        }
     }
 }
@@ -14,16 +14,77 @@ pub struct IgdbGame {
     pub videos: Option<Vec<IgdbVideo>>,
     pub screenshots: Option<Vec<IgdbImage>>,
     pub artworks: Option<Vec<IgdbImage>>,
+    pub involved_companies: Option<Vec<IgdbInvolvedCompany>>,
+    pub franchises: Option<Vec<IgdbNamed>>,
+    pub collections: Option<Vec<IgdbNamed>>,
+    pub themes: Option<Vec<IgdbNamed>>,
+    pub game_modes: Option<Vec<IgdbNamed>>,
+    pub player_perspectives: Option<Vec<IgdbNamed>>,
+    pub age_ratings: Option<Vec<IgdbAgeRating>>,
+    pub websites: Option<Vec<IgdbWebsite>>,
+    pub alternative_names: Option<Vec<IgdbNamed>>,
+    pub similar_games: Option<Vec<i64>>,
+    pub updated_at: Option<i64>,
+}
+
+// Any IGDB entity expanded only for its name (franchises, themes, game modes...)
+#[derive(Debug, Deserialize)]
+pub struct IgdbNamed {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IgdbInvolvedCompany {
+    pub company: Option<IgdbNamed>,
+    #[serde(default)]
+    pub developer: bool,
+    #[serde(default)]
+    pub publisher: bool,
+    #[serde(default)]
+    pub porting: bool,
+    #[serde(default)]
+    pub supporting: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IgdbAgeRating {
+    pub organization: Option<IgdbNamed>,
+    pub rating_category: Option<IgdbAgeRatingCategory>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IgdbAgeRatingCategory {
+    pub rating: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IgdbWebsite {
+    pub url: String,
+    #[serde(rename = "type")]
+    pub website_type: Option<IgdbWebsiteType>,
+    #[serde(default)]
+    pub trusted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IgdbWebsiteType {
+    #[serde(rename = "type")]
+    pub name: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct IgdbImage {
-    #[allow(dead_code)]
     pub id: i64,
-    pub url: Option<String>,
-    #[allow(dead_code)]
     pub image_id: Option<String>,
-    // Add other fields if needed
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    #[serde(default)]
+    pub alpha_channel: bool,
+    #[serde(default)]
+    pub animated: bool,
+    pub checksum: Option<String>,
+    pub artwork_type: Option<i64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -33,6 +94,28 @@ pub struct IgdbPlatform {
     pub generation: Option<i32>,
     #[serde(alias = "category")]
     pub platform_type: Option<i32>, // Maps to PlatformType (IGDB returns 'platform_type' or 'category')
+    pub abbreviation: Option<String>,
+    pub platform_family: Option<IgdbNamed>,
+    pub platform_logo: Option<IgdbImage>,
+    pub versions: Option<Vec<IgdbPlatformVersion>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IgdbPlatformVersion {
+    pub id: i64,
+    pub name: Option<String>,
+    pub platform_version_release_dates: Option<Vec<IgdbPlatformVersionReleaseDate>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IgdbPlatformVersionReleaseDate {
+    pub date: Option<i64>,
+    pub release_region: Option<IgdbReleaseRegion>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IgdbReleaseRegion {
+    pub region: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -56,3 +139,30 @@ pub struct TwitchTokenResponse {
     #[allow(dead_code)]
     pub token_type: String,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct IgdbReleaseDate {
+    pub id: i64,
+    pub date: Option<i64>,
+    pub human: Option<String>,
+    // Deprecated by IGDB in favour of `date_format`, still filled on older records.
+    pub category: Option<i32>,
+    pub date_format: Option<IgdbDateFormat>,
+    pub game: Option<IgdbNamed>,
+    pub platform: Option<IgdbNamed>,
+    pub release_region: Option<IgdbReleaseRegion>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IgdbDateFormat {
+    pub format: Option<String>,
+}
+
+// `game_time_to_beats` record; durations are in seconds.
+#[derive(Debug, Deserialize)]
+pub struct IgdbTimeToBeat {
+    pub game_id: i64,
+    pub hastily: Option<i64>,
+    pub normally: Option<i64>,
+    pub completely: Option<i64>,
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use crate::domain::error::DomainError;
+
+/// How `IgdbClient` talks to IGDB: over the network, over the network while saving every
+/// response to disk, or from previously saved responses only.
+#[derive(Debug, Clone, Default)]
+pub enum FixtureMode {
+    #[default]
+    Live,
+    Record(FixtureStore),
+    Replay(FixtureStore),
+}
+
+impl FixtureMode {
+    /// Builds the mode from its configuration name (`live`, `record` or `replay`).
+    pub fn parse(mode: &str, dir: impl Into<PathBuf>) -> Result<Self, String> {
+        match mode.trim().to_ascii_lowercase().as_str() {
+            "live" | "" => Ok(FixtureMode::Live),
+            "record" => Ok(FixtureMode::Record(FixtureStore::new(dir))),
+            "replay" => Ok(FixtureMode::Replay(FixtureStore::new(dir))),
+            other => Err(format!("Unknown IGDB mode '{}', expected live, record or replay", other)),
+        }
+    }
+
+    pub fn is_replay(&self) -> bool {
+        matches!(self, FixtureMode::Replay(_))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    endpoint: String,
+    body: String,
+    response: serde_json::Value,
+}
+
+/// Directory of recorded IGDB exchanges, one JSON file per distinct (endpoint, body) pair.
+#[derive(Debug, Clone)]
+pub struct FixtureStore {
+    dir: PathBuf,
+}
+
+impl FixtureStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    // `<endpoint>-<hash>.json`; FNV-1a keeps names stable across builds and toolchains.
+    fn path_for(&self, endpoint: &str, body: &str) -> PathBuf {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in endpoint.bytes().chain([0]).chain(body.trim().bytes()) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        let name: String = endpoint
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        self.dir.join(format!("{}-{:016x}.json", name, hash))
+    }
+
+    /// Returns the recorded response body for the request, or an `Upstream` error naming
+    /// the missing fixture file.
+    pub async fn load(&self, endpoint: &str, body: &str) -> Result<String, DomainError> {
+        let path = self.path_for(endpoint, body);
+        let content = tokio::fs::read_to_string(&path).await.map_err(|e| {
+            DomainError::Upstream(format!("No IGDB fixture for '{}' at {}: {}", endpoint, path.display(), e))
+        })?;
+        let fixture: Fixture = serde_json::from_str(&content)
+            .map_err(|e| DomainError::Internal(format!("Unreadable IGDB fixture {}: {}", path.display(), e)))?;
+
+        Ok(fixture.response.to_string())
+    }
+
+    pub async fn save(&self, endpoint: &str, body: &str, response: &str) -> Result<(), DomainError> {
+        let path = self.path_for(endpoint, body);
+        let fixture = Fixture {
+            endpoint: endpoint.to_string(),
+            body: body.trim().to_string(),
+            response: serde_json::from_str(response).unwrap_or_else(|_| serde_json::Value::String(response.to_string())),
+        };
+        let content = serde_json::to_string_pretty(&fixture).map_err(|e| DomainError::Internal(e.to_string()))?;
+
+        tokio::fs::create_dir_all(&self.dir).await.map_err(|e| DomainError::Internal(e.to_string()))?;
+        tokio::fs::write(&path, content).await.map_err(|e| DomainError::Internal(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn replays_what_was_recorded() {
+        let dir = std::env::temp_dir().join(format!("igdb-fixtures-{}", uuid::Uuid::new_v4()));
+        let store = FixtureStore::new(&dir);
+
+        store.save("games", "fields name; where id = 1;", r#"[{"id":1,"name":"Zelda"}]"#).await.unwrap();
+
+        let replayed = store.load("games", "fields name; where id = 1;\n").await.unwrap();
+        assert_eq!(replayed, r#"[{"id":1,"name":"Zelda"}]"#);
+        assert!(matches!(store.load("games", "fields name; where id = 2;").await, Err(DomainError::Upstream(_))));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -1,15 +1,20 @@
 use async_trait::async_trait;
+use futures::future::try_join_all;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use crate::application::ports::output::game_provider::GameProvider;
-use crate::domain::game::Game;
+use crate::domain::error::DomainError;
+use crate::domain::game::{AgeRating, Game, InvolvedCompany, TimeToBeat, Website};
+use crate::domain::game_filter::{GameFilter, GameSortField, SortDirection};
+use crate::domain::image::Image;
 use crate::domain::page::Page;
 use crate::infrastructure::igdb::client::IgdbClient;
-use crate::infrastructure::igdb::dtos::IgdbGame;
+use crate::infrastructure::igdb::dtos::{IgdbGame, IgdbImage, IgdbNamed, IgdbTimeToBeat};
+use crate::infrastructure::igdb::multiquery::MultiQuery;
+use crate::infrastructure::igdb::query::{ApicalypseQuery, Condition, SortOrder};
 
-#[derive(serde::Deserialize, Debug)]
-struct CountResponse {
-    count: i64,
-}
+// IGDB caps `limit` at 500 records per query.
+const MAX_IDS_PER_QUERY: usize = 500;
 
 pub struct IgdbGameProvider {
     client: Arc<IgdbClient>,
@@ -19,82 +24,215 @@ impl IgdbGameProvider {
     pub fn new(client: Arc<IgdbClient>) -> Self {
         Self { client }
     }
+
+    async fn fetch_game(&self, external_id: i64) -> Result<Option<Game>, DomainError> {
+        let query = game_query().where_(Condition::eq("id", external_id));
+
+        let games: Vec<IgdbGame> = self.client.post("games", query.build()).await?;
+        Ok(games.into_iter().next().map(map_igdb_game_to_domain))
+    }
 }
 
 #[async_trait]
 impl GameProvider for IgdbGameProvider {
-    async fn find_by_external_id(&self, external_id: i64) -> Result<Option<Game>, String> {
-        let query = format!(
-            "fields name, summary, storyline, first_release_date, rating, cover.url, platforms.name, genres.name, videos.video_id, screenshots.url, artworks.url; where id = {};",
-            external_id
-        );
-
-        let games: Vec<IgdbGame> = self.client.post("games", query).await?;
-
-        Ok(games.into_iter().next().map(map_igdb_game_to_domain))
+    // Only the detail lookup carries time-to-beat: it costs a second IGDB request, and lists
+    // don't show it. The backlog estimate asks for it through `find_time_to_beat`.
+    async fn find_by_external_id(&self, external_id: i64) -> Result<Option<Game>, DomainError> {
+        let mut game = self.fetch_game(external_id).await?;
+        attach_time_to_beat(&self.client, game.as_mut()).await;
+        Ok(game)
     }
 
-    async fn find_multiple_by_external_ids(&self, external_ids: &[i64]) -> Result<Vec<Game>, String> {
+    async fn find_multiple_by_external_ids(&self, external_ids: &[i64]) -> Result<Vec<Game>, DomainError> {
         if external_ids.is_empty() {
             return Ok(vec![]);
         }
 
-        let ids_str = external_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",");
-        let query = format!(
-            "fields name, summary, storyline, first_release_date, rating, cover.url, platforms.name, genres.name, videos.video_id, screenshots.url, artworks.url; where id = ({});",
-            ids_str
-        );
+        let mut seen = HashSet::new();
+        let unique_ids: Vec<i64> = external_ids.iter().copied().filter(|id| seen.insert(*id)).collect();
 
-        let games: Vec<IgdbGame> = self.client.post("games", query).await?;
-        Ok(games.into_iter().map(map_igdb_game_to_domain).collect())
+        // Each chunk fits in a single IGDB page; the client's rate limiter bounds concurrency.
+        let requests = unique_ids.chunks(MAX_IDS_PER_QUERY).map(|chunk| {
+            let query = game_query()
+                .where_(Condition::any_of("id", chunk.iter().copied()))
+                .limit(chunk.len() as u32);
+            self.client.post::<Vec<IgdbGame>>("games", query.build())
+        });
+
+        let mut found: HashMap<i64, Game> = try_join_all(requests).await?
+            .into_iter()
+            .flatten()
+            .map(|game| (game.id, map_igdb_game_to_domain(game)))
+            .collect();
+
+        Ok(unique_ids.iter().filter_map(|id| found.remove(id)).collect())
     }
 
-    async fn search_by_name(&self, name: &str) -> Result<Vec<Game>, String> {
-        let query = format!(
-            "fields name, summary, storyline, first_release_date, rating, cover.url, platforms.name, genres.name, videos.video_id, screenshots.url, artworks.url; search \"{}\"; limit 20;",
-            name
-        );
+    async fn search_by_name(&self, name: &str) -> Result<Vec<Game>, DomainError> {
+        let query = game_query().search(name).limit(20);
 
-        let games: Vec<IgdbGame> = self.client.post("games", query).await?;
+        let games: Vec<IgdbGame> = self.client.post("games", query.build()).await?;
         Ok(games.into_iter().map(map_igdb_game_to_domain).collect())
     }
 
-    async fn filter_games(&self, filter: &str, sort: &str, limit: i32, offset: i32) -> Result<Page<Game>, String> {
-        // 1. Get the games
-        let mut query = format!(
-            "fields name, summary, storyline, first_release_date, rating, cover.url, platforms.name, genres.name, videos.video_id, screenshots.url, artworks.url; limit {}; offset {};",
-            limit, offset
-        );
-
-        if !filter.is_empty() {
-            query.push_str(&format!(" where {};", filter));
+    async fn find_similar_games(&self, external_id: i64) -> Result<Option<Vec<Game>>, DomainError> {
+        match self.fetch_game(external_id).await? {
+            Some(game) => Ok(Some(self.find_multiple_by_external_ids(&game.similar_game_ids).await?)),
+            None => Ok(None),
         }
+    }
 
-        if !sort.is_empty() {
-            query.push_str(&format!(" sort {};", sort));
+    async fn filter_games(&self, filter: &GameFilter, limit: i32, offset: i32) -> Result<Page<Game>, DomainError> {
+        // 1. Get the games
+        let mut query = apply_filter(game_query(), filter)
+            .limit(limit.max(0) as u32)
+            .offset(offset.max(0) as u32);
+
+        if let Some(sort) = filter.sort {
+            let field = match sort.field {
+                GameSortField::Name => "name",
+                GameSortField::ReleaseDate => "first_release_date",
+                GameSortField::Rating => "rating",
+            };
+            let order = match sort.direction {
+                SortDirection::Asc => SortOrder::Asc,
+                SortDirection::Desc => SortOrder::Desc,
+            };
+            query = query.sort(field, order);
         }
 
-        let games: Vec<IgdbGame> = self.client.post("games", query).await?;
-        let domain_games: Vec<Game> = games.into_iter().map(map_igdb_game_to_domain).collect();
+        // 2. Get the total count in the same round trip
+        let count_query = apply_filter(ApicalypseQuery::new(), filter);
 
-        // 2. Get the total count
-        let mut count_query = String::new();
-        if !filter.is_empty() {
-            count_query.push_str(&format!("where {};", filter));
-        }
+        let multiquery = MultiQuery::new()
+            .query("games", "games", query)
+            .query("games/count", "count", count_query);
+        let mut response = self.client.multiquery(&multiquery).await?;
 
-        // IGDB count endpoint returns a single object { "count": 123 }, NOT a list
-        let count_response: CountResponse = self.client.post("games/count", count_query).await?;
-        let total_elements = count_response.count;
+        let games: Vec<IgdbGame> = response.take_results("games")?;
+        let domain_games: Vec<Game> = games.into_iter().map(map_igdb_game_to_domain).collect();
+        let total_elements = response.count("count")?;
 
         // Calculate page number (0-based)
         let page = if limit > 0 { offset / limit } else { 0 };
 
         Ok(Page::new(domain_games, page, limit, total_elements))
     }
+
+    async fn find_time_to_beat(&self, external_ids: &[i64]) -> Result<HashMap<i64, TimeToBeat>, DomainError> {
+        fetch_time_to_beat(&self.client, external_ids).await
+    }
+}
+
+pub const IMAGE_FIELDS: &[&str] = &["image_id", "width", "height", "alpha_channel", "animated", "checksum"];
+
+// Fields every game lookup needs to build a domain `Game`.
+pub fn game_query() -> ApicalypseQuery {
+    ApicalypseQuery::new()
+        .fields(&["name", "summary", "storyline", "first_release_date", "rating", "similar_games"])
+        .expand("cover", IMAGE_FIELDS)
+        .expand("platforms", &["name"])
+        .expand("genres", &["name"])
+        .expand("videos", &["video_id"])
+        .expand("screenshots", IMAGE_FIELDS)
+        .expand("artworks", IMAGE_FIELDS)
+        .expand("artworks", &["artwork_type"])
+        .expand("involved_companies", &["company.name", "developer", "publisher", "porting", "supporting"])
+        .expand("franchises", &["name"])
+        .expand("collections", &["name"])
+        .expand("themes", &["name"])
+        .expand("game_modes", &["name"])
+        .expand("player_perspectives", &["name"])
+        .expand("age_ratings", &["organization.name", "rating_category.rating"])
+        .expand("websites", &["url", "type.type", "trusted"])
+        .expand("alternative_names", &["name"])
+}
+
+/// Fills `time_to_beat` from IGDB's `game_time_to_beats` endpoint, which cannot be expanded
+/// from `games`. Games IGDB has no data for get an empty one, so caches don't ask again.
+/// The durations are secondary data, so a failed lookup only leaves them unset.
+pub async fn attach_time_to_beat<'a>(client: &IgdbClient, games: impl IntoIterator<Item = &'a mut Game>) {
+    let games: Vec<&mut Game> = games.into_iter().collect();
+    if games.is_empty() {
+        return;
+    }
+
+    let ids: Vec<i64> = games.iter().map(|game| game.id).collect();
+    let mut by_game = match fetch_time_to_beat(client, &ids).await {
+        Ok(by_game) => by_game,
+        Err(e) => {
+            tracing::warn!("Could not fetch time-to-beat for {} games: {}", games.len(), e);
+            return;
+        }
+    };
+
+    for game in games {
+        game.time_to_beat = Some(by_game.remove(&game.id).unwrap_or_default());
+    }
+}
+
+async fn fetch_time_to_beat(client: &IgdbClient, external_ids: &[i64]) -> Result<HashMap<i64, TimeToBeat>, DomainError> {
+    let requests = external_ids.chunks(MAX_IDS_PER_QUERY).map(|chunk| {
+        let query = ApicalypseQuery::new()
+            .fields(&["game_id", "hastily", "normally", "completely"])
+            .where_(Condition::any_of("game_id", chunk.iter().copied()))
+            .limit(chunk.len() as u32);
+        client.post::<Vec<IgdbTimeToBeat>>("game_time_to_beats", query.build())
+    });
+
+    Ok(try_join_all(requests).await?
+        .into_iter()
+        .flatten()
+        .map(|record| (record.game_id, TimeToBeat {
+            hastily: record.hastily,
+            normally: record.normally,
+            completely: record.completely,
+        }))
+        .filter(|(_, time_to_beat)| !time_to_beat.is_empty())
+        .collect())
+}
+
+// Translates the provider-neutral filter into Apicalypse conditions.
+fn apply_filter(mut query: ApicalypseQuery, filter: &GameFilter) -> ApicalypseQuery {
+    if !filter.platform_ids.is_empty() {
+        query = query.where_(Condition::any_of("platforms", filter.platform_ids.iter().copied()));
+    }
+
+    if !filter.genres.is_empty() {
+        query = query.where_(Condition::any_of("genres.name", filter.genres.iter().map(String::as_str)));
+    }
+
+    if let Some(from) = filter.release_year_from.and_then(start_of_year) {
+        query = query.where_(Condition::gte("first_release_date", from));
+    }
+
+    if let Some(to) = filter.release_year_to.and_then(|year| start_of_year(year + 1)) {
+        query = query.where_(Condition::lt("first_release_date", to));
+    }
+
+    // The domain rating is 0-10, IGDB stores 0-100
+    if let Some(min) = filter.rating_min {
+        query = query.where_(Condition::gte("rating", min * 10.0));
+    }
+
+    if let Some(max) = filter.rating_max {
+        query = query.where_(Condition::lte("rating", max * 10.0));
+    }
+
+    if let Some(name) = &filter.name_contains {
+        query = query.where_(Condition::contains_text("name", name));
+    }
+
+    query
+}
+
+fn start_of_year(year: i32) -> Option<i64> {
+    chrono::NaiveDate::from_ymd_opt(year, 1, 1)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc().timestamp())
 }
 
-fn map_igdb_game_to_domain(igdb_game: IgdbGame) -> Game {
+pub fn map_igdb_game_to_domain(igdb_game: IgdbGame) -> Game {
     Game {
         id: igdb_game.id,
         name: igdb_game.name,
@@ -102,11 +240,58 @@ fn map_igdb_game_to_domain(igdb_game: IgdbGame) -> Game {
         storyline: igdb_game.storyline,
         release_date: igdb_game.first_release_date.and_then(|ts| chrono::DateTime::from_timestamp(ts, 0).map(|dt| dt.date_naive())),
         rating: igdb_game.rating.map(|r| r / 10.0), // Convert 0-100 to 0-10
-        cover_image_url: igdb_game.cover.and_then(|c| c.url).map(|url| format!("https:{}", url.replace("t_thumb", "t_cover_big"))), // High quality cover
+        cover: igdb_game.cover.and_then(map_igdb_image),
         platforms: igdb_game.platforms.map(|p| p.into_iter().map(|pl| pl.name).collect()).unwrap_or_default(),
         genres: igdb_game.genres.map(|g| g.into_iter().map(|ge| ge.name).collect()).unwrap_or_default(),
         videos: igdb_game.videos.map(|v| v.into_iter().map(|vi| format!("https://www.youtube.com/watch?v={}", vi.video_id)).collect()).unwrap_or_default(),
-        screenshots: igdb_game.screenshots.map(|s| s.into_iter().filter_map(|sc| sc.url.map(|u| format!("https:{}", u.replace("t_thumb", "t_screenshot_big")))).collect()).unwrap_or_default(),
-        artworks: igdb_game.artworks.map(|a| a.into_iter().filter_map(|ar| ar.url.map(|u| crate::domain::artwork::Artwork { url: format!("https:{}", u.replace("t_thumb", "t_1080p")) })).collect()).unwrap_or_default(),
+        screenshots: igdb_game.screenshots.map(|s| s.into_iter().filter_map(map_igdb_image).collect()).unwrap_or_default(),
+        artworks: igdb_game.artworks.map(|a| a.into_iter().filter_map(map_igdb_image).collect()).unwrap_or_default(),
+        involved_companies: igdb_game.involved_companies.map(|c| c.into_iter().filter_map(|ic| {
+            ic.company.and_then(|company| company.name).map(|name| InvolvedCompany {
+                name,
+                developer: ic.developer,
+                publisher: ic.publisher,
+                porting: ic.porting,
+                supporting: ic.supporting,
+            })
+        }).collect()).unwrap_or_default(),
+        franchises: names(igdb_game.franchises),
+        collections: names(igdb_game.collections),
+        themes: names(igdb_game.themes),
+        game_modes: names(igdb_game.game_modes),
+        player_perspectives: names(igdb_game.player_perspectives),
+        age_ratings: igdb_game.age_ratings.map(|r| r.into_iter().filter_map(|ar| {
+            let organization = ar.organization.and_then(|o| o.name)?;
+            let rating = ar.rating_category.and_then(|c| c.rating)?;
+            Some(AgeRating { organization, rating })
+        }).collect()).unwrap_or_default(),
+        websites: igdb_game.websites.map(|w| w.into_iter().map(|site| Website {
+            url: site.url,
+            category: site.website_type.and_then(|t| t.name),
+            trusted: site.trusted,
+        }).collect()).unwrap_or_default(),
+        alternative_names: names(igdb_game.alternative_names),
+        similar_game_ids: igdb_game.similar_games.unwrap_or_default(),
+        time_to_beat: None,
+        sources: vec![],
+        stale: false,
     }
 }
+
+// Images without an `image_id` cannot be addressed on the IGDB image CDN, so they are dropped.
+pub fn map_igdb_image(image: IgdbImage) -> Option<Image> {
+    Some(Image {
+        id: image.id,
+        image_id: image.image_id?,
+        width: image.width,
+        height: image.height,
+        alpha_channel: image.alpha_channel,
+        animated: image.animated,
+        checksum: image.checksum,
+        artwork_type: image.artwork_type,
+    })
+}
+
+fn names(entities: Option<Vec<IgdbNamed>>) -> Vec<String> {
+    entities.map(|e| e.into_iter().filter_map(|entity| entity.name).collect()).unwrap_or_default()
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use crate::application::ports::output::game_suggestion_provider::GameSuggestionProvider;
+use crate::domain::error::DomainError;
+use crate::domain::game_suggestion::GameSuggestion;
+use crate::infrastructure::igdb::client::IgdbClient;
+use crate::infrastructure::igdb::dtos::IgdbGame;
+use crate::infrastructure::igdb::game_provider::{map_igdb_game_to_domain, IMAGE_FIELDS};
+use crate::infrastructure::igdb::query::ApicalypseQuery;
+
+// IGDB returns at most 500 records per query.
+const MAX_LIMIT: usize = 500;
+
+/// Name search that only asks IGDB for the fields a suggestion needs.
+pub struct IgdbGameSuggestionProvider {
+    client: Arc<IgdbClient>,
+}
+
+impl IgdbGameSuggestionProvider {
+    pub fn new(client: Arc<IgdbClient>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl GameSuggestionProvider for IgdbGameSuggestionProvider {
+    async fn suggest(&self, query: &str, limit: usize) -> Result<Vec<GameSuggestion>, DomainError> {
+        let igdb_query = ApicalypseQuery::new()
+            .fields(&["name", "first_release_date"])
+            .expand("cover", IMAGE_FIELDS)
+            .search(query)
+            .limit(limit.clamp(1, MAX_LIMIT) as u32);
+
+        let games: Vec<IgdbGame> = self.client.post("games", igdb_query.build()).await?;
+        Ok(games
+            .into_iter()
+            .map(|game| GameSuggestion::from(&map_igdb_game_to_domain(game)))
+            .collect())
+    }
+}
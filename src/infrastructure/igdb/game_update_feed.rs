@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use crate::application::ports::output::game_update_feed::GameUpdateFeed;
+use crate::domain::catalog::{CatalogGame, SyncCursor};
+use crate::domain::error::DomainError;
+use crate::infrastructure::igdb::client::IgdbClient;
+use crate::infrastructure::igdb::dtos::IgdbGame;
+use crate::infrastructure::igdb::game_provider::{attach_time_to_beat, game_query, map_igdb_game_to_domain};
+use crate::infrastructure::igdb::multiquery::MultiQuery;
+use crate::infrastructure::igdb::query::{ApicalypseQuery, Condition, SortOrder};
+
+#[derive(Debug, serde::Deserialize)]
+struct IgdbCount {
+    count: i64,
+}
+
+/// Pages through IGDB `games` in `(updated_at, id)` order for the catalog sync job.
+///
+/// IGDB sorts on a single field, so a page is read in two parts: the games sharing the
+/// cursor's timestamp, sorted by id, then newer games sorted by `updated_at`. When the newer
+/// games fill the page, the last timestamp may continue past it; its games are re-read by id
+/// so the page ends on the lowest ids of that timestamp and the cursor skips none of them.
+pub struct IgdbGameUpdateFeed {
+    client: Arc<IgdbClient>,
+}
+
+impl IgdbGameUpdateFeed {
+    pub fn new(client: Arc<IgdbClient>) -> Self {
+        Self { client }
+    }
+}
+
+// Games after the cursor: newer ones, plus those sharing its timestamp with a higher id.
+fn after(cursor: &SyncCursor) -> Condition {
+    Condition::any([
+        Condition::gt("updated_at", cursor.updated_at),
+        Condition::all([
+            Condition::eq("updated_at", cursor.updated_at),
+            Condition::gt("id", cursor.game_id),
+        ]),
+    ])
+}
+
+fn same_timestamp(updated_at: i64, after_id: i64) -> Condition {
+    Condition::all([
+        Condition::eq("updated_at", updated_at),
+        Condition::gt("id", after_id),
+    ])
+}
+
+fn feed_query(condition: Condition) -> ApicalypseQuery {
+    game_query().fields(&["updated_at"]).where_(condition)
+}
+
+// Removes the games sharing the last timestamp of a non-empty page. Returns that timestamp
+// and how many games were removed.
+fn trailing_tie(games: &mut Vec<IgdbGame>) -> (i64, usize) {
+    let last_updated_at = games.last().and_then(|game| game.updated_at);
+    let tied = games.iter().rev().take_while(|game| game.updated_at == last_updated_at).count();
+    games.truncate(games.len() - tied);
+    (last_updated_at.unwrap_or_default(), tied)
+}
+
+#[async_trait]
+impl GameUpdateFeed for IgdbGameUpdateFeed {
+    async fn fetch_updated_since(&self, cursor: &SyncCursor, limit: i32) -> Result<Vec<CatalogGame>, DomainError> {
+        let limit = limit.max(1) as usize;
+        let multiquery = MultiQuery::new()
+            .query("games", "tied", feed_query(same_timestamp(cursor.updated_at, cursor.game_id)).sort("id", SortOrder::Asc).limit(limit as u32))
+            .query("games", "newer", feed_query(Condition::gt("updated_at", cursor.updated_at)).sort("updated_at", SortOrder::Asc).limit(limit as u32));
+        let mut response = self.client.multiquery(&multiquery).await?;
+        let mut games: Vec<IgdbGame> = response.take_results("tied")?;
+        let mut newer: Vec<IgdbGame> = response.take_results("newer")?;
+
+        let room = limit.saturating_sub(games.len());
+        if room > 0 && !newer.is_empty() {
+            let may_continue = newer.len() >= room;
+            newer.truncate(room);
+            if may_continue {
+                let (last_updated_at, tied) = trailing_tie(&mut newer);
+                let query = feed_query(Condition::eq("updated_at", last_updated_at)).sort("id", SortOrder::Asc).limit(tied as u32);
+                let lowest_ids: Vec<IgdbGame> = self.client.post("games", query.build()).await?;
+                newer.extend(lowest_ids);
+            }
+            games.extend(newer);
+        }
+
+        let mut catalog_games: Vec<CatalogGame> = games.into_iter().map(|igdb_game| {
+            let platform_ids = igdb_game.platforms.iter().flatten().map(|p| p.id).collect();
+            let updated_at = igdb_game.updated_at.unwrap_or_default();
+            CatalogGame {
+                game: map_igdb_game_to_domain(igdb_game),
+                platform_ids,
+                updated_at,
+            }
+        }).collect();
+
+        attach_time_to_beat(&self.client, catalog_games.iter_mut().map(|catalog_game| &mut catalog_game.game)).await;
+        Ok(catalog_games)
+    }
+
+    async fn count_updated_since(&self, cursor: &SyncCursor) -> Result<i64, DomainError> {
+        let query = ApicalypseQuery::new().where_(after(cursor));
+        let count: IgdbCount = self.client.post("games/count", query.build()).await?;
+        Ok(count.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(id: i64, updated_at: i64) -> IgdbGame {
+        serde_json::from_value(serde_json::json!({ "id": id, "name": "Game", "updated_at": updated_at })).unwrap()
+    }
+
+    #[test]
+    fn trailing_tie_removes_every_game_of_the_last_timestamp() {
+        let mut games = vec![game(9, 100), game(7, 200), game(3, 200)];
+        assert_eq!(trailing_tie(&mut games), (200, 2));
+        assert_eq!(games.iter().map(|game| game.id).collect::<Vec<_>>(), vec![9]);
+
+        let mut games = vec![game(5, 300), game(4, 300)];
+        assert_eq!(trailing_tie(&mut games), (300, 2));
+        assert!(games.is_empty());
+    }
+}
@@ -0,0 +1,44 @@
+use crate::application::ports::output::image_url_resolver::ImageUrlResolver;
+use crate::domain::image::{Image, ImageSize};
+
+const IMAGE_BASE_URL: &str = "https://images.igdb.com/igdb/image/upload";
+
+/// Builds URLs on the IGDB image CDN, which serves every image in every preset size.
+pub struct IgdbImageUrls;
+
+impl ImageUrlResolver for IgdbImageUrls {
+    fn image_url(&self, image: &Image, size: ImageSize) -> String {
+        format!("{}/t_{}/{}.jpg", IMAGE_BASE_URL, size, image.image_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::image::ImagePreset;
+
+    fn image(image_id: &str) -> Image {
+        Image {
+            id: 1,
+            image_id: image_id.to_string(),
+            width: None,
+            height: None,
+            alpha_channel: false,
+            animated: false,
+            checksum: None,
+            artwork_type: None,
+        }
+    }
+
+    #[test]
+    fn builds_urls_for_presets_and_retina_variants() {
+        assert_eq!(
+            IgdbImageUrls.image_url(&image("co1wyy"), ImageSize::COVER),
+            "https://images.igdb.com/igdb/image/upload/t_cover_big/co1wyy.jpg"
+        );
+        assert_eq!(
+            IgdbImageUrls.image_url(&image("sc6kx1"), ImageSize::new(ImagePreset::P720).retina()),
+            "https://images.igdb.com/igdb/image/upload/t_720p_2x/sc6kx1.jpg"
+        );
+    }
+}
@@ -1,4 +1,17 @@
 pub mod client;
+pub mod fixtures;
 pub mod game_provider;
 pub mod platform_provider;
 pub mod dtos;
+pub mod rate_limiter;
+pub mod retry;
+pub mod circuit_breaker;
+pub mod token_provider;
+pub mod request_coalescer;
+pub mod query;
+pub mod multiquery;
+pub mod reference_data_provider;
+pub mod image_url;
+pub mod game_update_feed;
+pub mod game_suggestion_provider;
+pub mod release_provider;
@@ -0,0 +1,118 @@
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::domain::error::DomainError;
+use crate::infrastructure::igdb::query::{quote, ApicalypseQuery};
+
+// IGDB rejects multiqueries with more than 10 sub-queries.
+pub const MAX_SUB_QUERIES: usize = 10;
+
+/// Several named Apicalypse queries sent to IGDB's `multiquery` endpoint in one request.
+#[derive(Debug, Clone, Default)]
+pub struct MultiQuery {
+    queries: Vec<(String, String, ApicalypseQuery)>,
+}
+
+impl MultiQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sub-query against `endpoint` (e.g. `games` or `games/count`) whose result
+    /// is later looked up by `name`.
+    pub fn query(mut self, endpoint: &str, name: &str, query: ApicalypseQuery) -> Self {
+        self.queries.push((endpoint.to_string(), name.to_string(), query));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    pub fn build(&self) -> String {
+        self.queries
+            .iter()
+            .map(|(endpoint, name, query)| format!("query {} {} {{ {} }};", endpoint, quote(name), query.build()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MultiQueryResult {
+    pub name: String,
+    pub result: Option<Value>,
+    pub count: Option<i64>,
+}
+
+/// Response of a multiquery, giving typed access to each named sub-result.
+#[derive(Debug)]
+pub struct MultiQueryResponse {
+    results: Vec<MultiQueryResult>,
+}
+
+impl MultiQueryResponse {
+    pub fn new(results: Vec<MultiQueryResult>) -> Self {
+        Self { results }
+    }
+
+    /// Takes the records returned by the sub-query called `name`.
+    pub fn take_results<T: DeserializeOwned>(&mut self, name: &str) -> Result<Vec<T>, DomainError> {
+        let entry = self.find_mut(name)?;
+        let value = entry.result.take().unwrap_or(Value::Array(vec![]));
+        serde_json::from_value(value)
+            .map_err(|e| DomainError::Upstream(format!("JSON Deserialization Error in multiquery '{}': {}", name, e)))
+    }
+
+    /// Returns the count produced by a `.../count` sub-query called `name`.
+    pub fn count(&mut self, name: &str) -> Result<i64, DomainError> {
+        self.find_mut(name)?
+            .count
+            .ok_or_else(|| DomainError::Upstream(format!("Multiquery result '{}' has no count", name)))
+    }
+
+    fn find_mut(&mut self, name: &str) -> Result<&mut MultiQueryResult, DomainError> {
+        self.results
+            .iter_mut()
+            .find(|r| r.name == name)
+            .ok_or_else(|| DomainError::Upstream(format!("Multiquery result '{}' missing from response", name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::igdb::query::Condition;
+
+    #[test]
+    fn builds_named_sub_queries() {
+        let multiquery = MultiQuery::new()
+            .query("games", "games", ApicalypseQuery::new().fields(&["name"]).limit(10))
+            .query("games/count", "count", ApicalypseQuery::new().where_(Condition::eq("id", 1_i64)));
+
+        assert_eq!(
+            multiquery.build(),
+            "query games \"games\" { fields name; limit 10; };\nquery games/count \"count\" { where id = 1; };"
+        );
+    }
+
+    #[test]
+    fn reads_results_and_counts_by_name() {
+        let raw = r#"[{"name":"games","result":[{"id":1},{"id":2}]},{"name":"count","count":42}]"#;
+        let mut response = MultiQueryResponse::new(serde_json::from_str(raw).unwrap());
+
+        #[derive(Deserialize)]
+        struct Id {
+            id: i64,
+        }
+
+        let ids: Vec<Id> = response.take_results("games").unwrap();
+        assert_eq!(ids.iter().map(|i| i.id).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(response.count("count").unwrap(), 42);
+        assert!(response.count("missing").is_err());
+    }
+}
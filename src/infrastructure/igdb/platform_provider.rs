@@ -1,9 +1,15 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 use crate::application::ports::output::platform_provider::PlatformProvider;
-use crate::domain::platform::{Platform, PlatformType};
+use crate::domain::error::DomainError;
+use crate::domain::page::Page;
+use crate::domain::platform::{Platform, PlatformFamily, PlatformReleaseDate, PlatformType, PlatformVersion};
+use crate::domain::platform_filter::PlatformFilter;
 use crate::infrastructure::igdb::client::IgdbClient;
-use crate::infrastructure::igdb::dtos::IgdbPlatform;
+use crate::infrastructure::igdb::dtos::{IgdbPlatform, IgdbPlatformVersion};
+use crate::infrastructure::igdb::game_provider::{map_igdb_image, IMAGE_FIELDS};
+use crate::infrastructure::igdb::multiquery::MultiQuery;
+use crate::infrastructure::igdb::query::{ApicalypseQuery, Condition, SortOrder};
 
 pub struct IgdbPlatformProvider {
     client: Arc<IgdbClient>,
@@ -17,21 +23,77 @@ impl IgdbPlatformProvider {
 
 #[async_trait]
 impl PlatformProvider for IgdbPlatformProvider {
-    async fn list_platforms(&self) -> Result<Vec<Platform>, String> {
-        // Request platform_type instead of category
-        let query = "fields name, generation, platform_type; limit 500; sort name asc;";
-        let platforms: Vec<IgdbPlatform> = self.client.post("platforms", query.to_string()).await?;
-
-        Ok(platforms.into_iter().map(|p| {
-            let type_id = p.platform_type.unwrap_or(0);
-            let platform_type = PlatformType::try_from(type_id).unwrap_or(PlatformType::Unknown);
-
-            Platform {
-                id: p.id,
-                name: p.name,
-                generation: p.generation,
-                platform_type,
-            }
-        }).collect())
+    async fn find_by_id(&self, id: i64) -> Result<Option<Platform>, DomainError> {
+        let query = platform_query().where_(Condition::eq("id", id)).limit(1);
+        let platforms: Vec<IgdbPlatform> = self.client.post("platforms", query.build()).await?;
+
+        Ok(platforms.into_iter().next().map(map_igdb_platform_to_domain))
+    }
+
+    async fn list_platforms(&self, filter: &PlatformFilter, limit: i32, offset: i32) -> Result<Page<Platform>, DomainError> {
+        let query = apply_filter(platform_query(), filter)
+            .sort("name", SortOrder::Asc)
+            .limit(limit.max(0) as u32)
+            .offset(offset.max(0) as u32);
+        let count_query = apply_filter(ApicalypseQuery::new(), filter);
+
+        let multiquery = MultiQuery::new()
+            .query("platforms", "platforms", query)
+            .query("platforms/count", "count", count_query);
+        let mut response = self.client.multiquery(&multiquery).await?;
+
+        let platforms: Vec<IgdbPlatform> = response.take_results("platforms")?;
+        let total_elements = response.count("count")?;
+        let page = if limit > 0 { offset / limit } else { 0 };
+
+        Ok(Page::new(platforms.into_iter().map(map_igdb_platform_to_domain).collect(), page, limit, total_elements))
+    }
+}
+
+fn platform_query() -> ApicalypseQuery {
+    ApicalypseQuery::new()
+        .fields(&["name", "abbreviation", "generation", "platform_type"])
+        .expand("platform_family", &["name"])
+        .expand("platform_logo", IMAGE_FIELDS)
+        .expand("versions", &["name", "platform_version_release_dates.date", "platform_version_release_dates.release_region.region"])
+}
+
+fn apply_filter(mut query: ApicalypseQuery, filter: &PlatformFilter) -> ApicalypseQuery {
+    if !filter.platform_types.is_empty() {
+        query = query.where_(Condition::any_of("platform_type", filter.platform_types.iter().map(|t| i32::from(*t) as i64)));
+    }
+    if let Some(generation) = filter.generation {
+        query = query.where_(Condition::eq("generation", generation as i64));
+    }
+    if let Some(family_id) = filter.family_id {
+        query = query.where_(Condition::eq("platform_family", family_id));
+    }
+    query
+}
+
+fn map_igdb_platform_to_domain(p: IgdbPlatform) -> Platform {
+    let type_id = p.platform_type.unwrap_or(0);
+    let platform_type = PlatformType::try_from(type_id).unwrap_or(PlatformType::Unknown);
+
+    Platform {
+        id: p.id,
+        name: p.name,
+        generation: p.generation,
+        platform_type,
+        abbreviation: p.abbreviation,
+        family: p.platform_family.and_then(|f| f.name.map(|name| PlatformFamily { id: f.id, name })),
+        logo: p.platform_logo.and_then(map_igdb_image),
+        versions: p.versions.map(|v| v.into_iter().map(map_igdb_platform_version).collect()).unwrap_or_default(),
+    }
+}
+
+fn map_igdb_platform_version(version: IgdbPlatformVersion) -> PlatformVersion {
+    PlatformVersion {
+        id: version.id,
+        name: version.name.unwrap_or_default(),
+        release_dates: version.platform_version_release_dates.map(|dates| dates.into_iter().map(|d| PlatformReleaseDate {
+            date: d.date.and_then(|ts| chrono::DateTime::from_timestamp(ts, 0).map(|dt| dt.date_naive())),
+            region: d.release_region.and_then(|r| r.region),
+        }).collect()).unwrap_or_default(),
     }
 }
@@ -0,0 +1,281 @@
+use std::fmt;
+
+/// A literal value in an Apicalypse condition. Text is always quoted and escaped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl From<i64> for Literal {
+    fn from(value: i64) -> Self {
+        Literal::Int(value)
+    }
+}
+
+impl From<f64> for Literal {
+    fn from(value: f64) -> Self {
+        Literal::Float(value)
+    }
+}
+
+impl From<&str> for Literal {
+    fn from(value: &str) -> Self {
+        Literal::Text(value.to_string())
+    }
+}
+
+impl From<String> for Literal {
+    fn from(value: String) -> Self {
+        Literal::Text(value)
+    }
+}
+
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Literal::Int(value) => write!(f, "{}", value),
+            Literal::Float(value) => write!(f, "{}", value),
+            Literal::Text(value) => write!(f, "{}", quote(value)),
+        }
+    }
+}
+
+/// A single `where` condition. Field names come from our own code; values are escaped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition(String);
+
+impl Condition {
+    pub fn eq(field: &str, value: impl Into<Literal>) -> Self {
+        Condition(format!("{} = {}", field, value.into()))
+    }
+
+    /// Matches when the field (or any element of an array field) equals one of `values`.
+    pub fn any_of<L: Into<Literal>>(field: &str, values: impl IntoIterator<Item = L>) -> Self {
+        let values = values.into_iter().map(|v| v.into().to_string()).collect::<Vec<_>>().join(",");
+        Condition(format!("{} = ({})", field, values))
+    }
+
+    pub fn gte(field: &str, value: impl Into<Literal>) -> Self {
+        Condition(format!("{} >= {}", field, value.into()))
+    }
+
+    pub fn gt(field: &str, value: impl Into<Literal>) -> Self {
+        Condition(format!("{} > {}", field, value.into()))
+    }
+
+    pub fn lt(field: &str, value: impl Into<Literal>) -> Self {
+        Condition(format!("{} < {}", field, value.into()))
+    }
+
+    pub fn lte(field: &str, value: impl Into<Literal>) -> Self {
+        Condition(format!("{} <= {}", field, value.into()))
+    }
+
+    /// Matches when every condition holds; parenthesized so it can be nested in `or`.
+    pub fn all(conditions: impl IntoIterator<Item = Condition>) -> Self {
+        Condition::join(conditions, " & ")
+    }
+
+    /// Matches when at least one condition holds.
+    pub fn any(conditions: impl IntoIterator<Item = Condition>) -> Self {
+        Condition::join(conditions, " | ")
+    }
+
+    fn join(conditions: impl IntoIterator<Item = Condition>, operator: &str) -> Self {
+        let parts = conditions.into_iter().map(|c| format!("({})", c.0)).collect::<Vec<_>>();
+        Condition(parts.join(operator))
+    }
+
+    /// Case-insensitive substring match.
+    pub fn contains_text(field: &str, text: &str) -> Self {
+        Condition(format!("{} ~ *{}*", field, quote(text)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// Typed builder for IGDB Apicalypse query bodies.
+#[derive(Debug, Clone, Default)]
+pub struct ApicalypseQuery {
+    fields: Vec<String>,
+    exclude: Vec<String>,
+    search: Option<String>,
+    conditions: Vec<Condition>,
+    sort: Option<(String, SortOrder)>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+impl ApicalypseQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.fields.extend(fields.iter().map(|f| f.to_string()));
+        self
+    }
+
+    /// Requests sub-fields of a related entity, e.g. `expand("cover", &["url"])` -> `cover.url`.
+    pub fn expand(mut self, relation: &str, sub_fields: &[&str]) -> Self {
+        self.fields.extend(sub_fields.iter().map(|f| format!("{}.{}", relation, f)));
+        self
+    }
+
+    pub fn exclude(mut self, fields: &[&str]) -> Self {
+        self.exclude.extend(fields.iter().map(|f| f.to_string()));
+        self
+    }
+
+    pub fn search(mut self, term: &str) -> Self {
+        self.search = Some(term.to_string());
+        self
+    }
+
+    /// Adds a condition; multiple conditions are combined with `&`.
+    pub fn where_(mut self, condition: Condition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn sort(mut self, field: &str, order: SortOrder) -> Self {
+        self.sort = Some((field.to_string(), order));
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn build(&self) -> String {
+        let mut clauses = Vec::new();
+
+        if !self.fields.is_empty() {
+            clauses.push(format!("fields {};", self.fields.join(", ")));
+        }
+        if !self.exclude.is_empty() {
+            clauses.push(format!("exclude {};", self.exclude.join(", ")));
+        }
+        if let Some(term) = &self.search {
+            clauses.push(format!("search {};", quote(term)));
+        }
+        if !self.conditions.is_empty() {
+            let conditions = self.conditions.iter().map(|c| c.0.as_str()).collect::<Vec<_>>().join(" & ");
+            clauses.push(format!("where {};", conditions));
+        }
+        if let Some((field, order)) = &self.sort {
+            let order = match order {
+                SortOrder::Asc => "asc",
+                SortOrder::Desc => "desc",
+            };
+            clauses.push(format!("sort {} {};", field, order));
+        }
+        if let Some(limit) = self.limit {
+            clauses.push(format!("limit {};", limit));
+        }
+        if let Some(offset) = self.offset {
+            clauses.push(format!("offset {};", offset));
+        }
+
+        clauses.join(" ")
+    }
+}
+
+// Apicalypse string literal with backslashes and double quotes escaped.
+pub fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_fields_and_expanded_sub_fields() {
+        let query = ApicalypseQuery::new()
+            .fields(&["name", "rating"])
+            .expand("cover", &["url", "image_id"])
+            .build();
+
+        assert_eq!(query, "fields name, rating, cover.url, cover.image_id;");
+    }
+
+    #[test]
+    fn builds_every_clause_in_order() {
+        let query = ApicalypseQuery::new()
+            .fields(&["name"])
+            .exclude(&["summary"])
+            .search("zelda")
+            .where_(Condition::eq("version_parent", 0))
+            .sort("rating", SortOrder::Desc)
+            .limit(20)
+            .offset(40)
+            .build();
+
+        assert_eq!(
+            query,
+            "fields name; exclude summary; search \"zelda\"; where version_parent = 0; sort rating desc; limit 20; offset 40;"
+        );
+    }
+
+    #[test]
+    fn escapes_quotes_and_backslashes_in_search() {
+        let query = ApicalypseQuery::new().search(r#"Tom "Clancy" \ Ghost"#).build();
+
+        assert_eq!(query, r#"search "Tom \"Clancy\" \\ Ghost";"#);
+    }
+
+    #[test]
+    fn cannot_break_out_of_a_quoted_value() {
+        let query = ApicalypseQuery::new()
+            .where_(Condition::contains_text("name", "\"; fields *; where id = 1"))
+            .build();
+
+        assert_eq!(query, r#"where name ~ *"\"; fields *; where id = 1"*;"#);
+    }
+
+    #[test]
+    fn combines_conditions_with_and() {
+        let query = ApicalypseQuery::new()
+            .where_(Condition::any_of("platforms", [6_i64, 48]))
+            .where_(Condition::any_of("genres.name", ["Shooter", "Role-playing (RPG)"]))
+            .where_(Condition::gte("first_release_date", 1262304000_i64))
+            .where_(Condition::lt("first_release_date", 1609459200_i64))
+            .where_(Condition::lte("rating", 90.5))
+            .build();
+
+        assert_eq!(
+            query,
+            "where platforms = (6,48) & genres.name = (\"Shooter\",\"Role-playing (RPG)\") & first_release_date >= 1262304000 & first_release_date < 1609459200 & rating <= 90.5;"
+        );
+    }
+
+    #[test]
+    fn nests_and_inside_or() {
+        let query = ApicalypseQuery::new()
+            .where_(Condition::any([
+                Condition::gt("updated_at", 1700000000_i64),
+                Condition::all([Condition::eq("updated_at", 1700000000_i64), Condition::gt("id", 42_i64)]),
+            ]))
+            .build();
+
+        assert_eq!(query, "where (updated_at > 1700000000) | ((updated_at = 1700000000) & (id > 42));");
+    }
+
+    #[test]
+    fn empty_query_builds_empty_body() {
+        assert_eq!(ApicalypseQuery::new().build(), "");
+    }
+}
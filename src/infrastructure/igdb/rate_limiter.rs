@@ -0,0 +1,60 @@
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, Semaphore, SemaphorePermit};
+
+/// Client-side limiter for IGDB: a token bucket caps the request rate and a
+/// semaphore caps the number of requests in flight. One instance is shared by
+/// every provider through the `IgdbClient`.
+pub struct RateLimiter {
+    in_flight: Semaphore,
+    bucket: Mutex<TokenBucket>,
+}
+
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_second: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        self.last_refill = now;
+    }
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_second: u32, max_in_flight: usize) -> Self {
+        let rate = requests_per_second.max(1) as f64;
+        Self {
+            in_flight: Semaphore::new(max_in_flight.max(1)),
+            bucket: Mutex::new(TokenBucket {
+                capacity: rate,
+                tokens: rate,
+                refill_per_second: rate,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits for a free in-flight slot and a rate token. The returned permit
+    /// must be held until the response has been fully read.
+    pub async fn acquire(&self) -> SemaphorePermit<'_> {
+        let permit = self.in_flight.acquire().await.expect("rate limiter semaphore is never closed");
+
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                bucket.refill();
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return permit;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / bucket.refill_per_second)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use crate::application::ports::output::reference_data_provider::ReferenceDataProvider;
+use crate::domain::error::DomainError;
+use crate::domain::reference_data::{ReferenceDataKind, ReferenceItem};
+use crate::infrastructure::igdb::client::IgdbClient;
+use crate::infrastructure::igdb::dtos::IgdbNamed;
+use crate::infrastructure::igdb::query::{ApicalypseQuery, SortOrder};
+
+pub struct IgdbReferenceDataProvider {
+    client: Arc<IgdbClient>,
+}
+
+impl IgdbReferenceDataProvider {
+    pub fn new(client: Arc<IgdbClient>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl ReferenceDataProvider for IgdbReferenceDataProvider {
+    async fn list(&self, kind: ReferenceDataKind) -> Result<Vec<ReferenceItem>, DomainError> {
+        let endpoint = match kind {
+            ReferenceDataKind::Genre => "genres",
+            ReferenceDataKind::Theme => "themes",
+            ReferenceDataKind::GameMode => "game_modes",
+        };
+        // Each of these lists has a few dozen entries, well under IGDB's 500 limit.
+        let query = ApicalypseQuery::new()
+            .fields(&["name"])
+            .sort("name", SortOrder::Asc)
+            .limit(500);
+        let items: Vec<IgdbNamed> = self.client.post(endpoint, query.build()).await?;
+
+        Ok(items
+            .into_iter()
+            .filter_map(|item| item.name.map(|name| ReferenceItem { id: item.id, name }))
+            .collect())
+    }
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::Arc;
+use crate::application::ports::output::release_provider::ReleaseProvider;
+use crate::domain::error::DomainError;
+use crate::domain::page::Page;
+use crate::domain::release::{DatePrecision, Release, ReleaseFilter};
+use crate::infrastructure::igdb::client::IgdbClient;
+use crate::infrastructure::igdb::dtos::IgdbReleaseDate;
+use crate::infrastructure::igdb::multiquery::MultiQuery;
+use crate::infrastructure::igdb::query::{ApicalypseQuery, Condition, SortOrder};
+
+pub struct IgdbReleaseProvider {
+    client: Arc<IgdbClient>,
+}
+
+impl IgdbReleaseProvider {
+    pub fn new(client: Arc<IgdbClient>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl ReleaseProvider for IgdbReleaseProvider {
+    async fn find_releases(&self, filter: &ReleaseFilter, limit: i32, offset: i32) -> Result<Page<Release>, DomainError> {
+        let query = apply_filter(release_query(), filter)
+            .sort("date", SortOrder::Asc)
+            .limit(limit.max(0) as u32)
+            .offset(offset.max(0) as u32);
+        let count_query = apply_filter(ApicalypseQuery::new(), filter);
+
+        let multiquery = MultiQuery::new()
+            .query("release_dates", "releases", query)
+            .query("release_dates/count", "count", count_query);
+        let mut response = self.client.multiquery(&multiquery).await?;
+
+        let releases: Vec<IgdbReleaseDate> = response.take_results("releases")?;
+        let total_elements = response.count("count")?;
+        let page = if limit > 0 { offset / limit } else { 0 };
+
+        Ok(Page::new(releases.into_iter().map(map_igdb_release_date).collect(), page, limit, total_elements))
+    }
+}
+
+fn release_query() -> ApicalypseQuery {
+    ApicalypseQuery::new()
+        .fields(&["date", "human", "category"])
+        .expand("date_format", &["format"])
+        .expand("game", &["name"])
+        .expand("platform", &["name"])
+        .expand("release_region", &["region"])
+}
+
+fn apply_filter(mut query: ApicalypseQuery, filter: &ReleaseFilter) -> ApicalypseQuery {
+    query = query
+        .where_(Condition::gte("date", start_of_day(filter.from)))
+        .where_(Condition::lt("date", start_of_day(filter.to) + 86_400));
+    if !filter.platform_ids.is_empty() {
+        query = query.where_(Condition::any_of("platform", filter.platform_ids.iter().copied()));
+    }
+    if let Some(region) = filter.region {
+        query = query.where_(Condition::eq("release_region", i32::from(region) as i64));
+    }
+    query
+}
+
+fn start_of_day(date: NaiveDate) -> i64 {
+    date.and_hms_opt(0, 0, 0).map(|dt| dt.and_utc().timestamp()).unwrap_or_default()
+}
+
+fn map_igdb_release_date(release: IgdbReleaseDate) -> Release {
+    let date = release.date.and_then(|ts| chrono::DateTime::from_timestamp(ts, 0).map(|dt| dt.date_naive()));
+    let precision = release.date_format
+        .and_then(|f| f.format)
+        .and_then(|format| precision_from_format(&format))
+        .or_else(|| release.category.and_then(precision_from_category))
+        .unwrap_or(if date.is_some() { DatePrecision::Day } else { DatePrecision::Tbd });
+
+    Release {
+        id: release.id,
+        game_id: release.game.as_ref().map(|g| g.id).unwrap_or_default(),
+        game_name: release.game.and_then(|g| g.name),
+        platform_id: release.platform.as_ref().map(|p| p.id),
+        platform_name: release.platform.and_then(|p| p.name),
+        region: release.release_region.and_then(|r| r.region),
+        date,
+        precision,
+        human: release.human,
+    }
+}
+
+// `date_formats.format` values, e.g. `YYYYMMMMDD` or `YYYYQ3`.
+fn precision_from_format(format: &str) -> Option<DatePrecision> {
+    match format {
+        "YYYYMMMMDD" => Some(DatePrecision::Day),
+        "YYYYMMMM" => Some(DatePrecision::Month),
+        "YYYY" => Some(DatePrecision::Year),
+        "TBD" => Some(DatePrecision::Tbd),
+        f if f.starts_with("YYYYQ") => Some(DatePrecision::Quarter),
+        _ => None,
+    }
+}
+
+// The deprecated `category` enum: 0 day, 1 month, 2 year, 3-6 quarters, 7 TBD.
+fn precision_from_category(category: i32) -> Option<DatePrecision> {
+    match category {
+        0 => Some(DatePrecision::Day),
+        1 => Some(DatePrecision::Month),
+        2 => Some(DatePrecision::Year),
+        3..=6 => Some(DatePrecision::Quarter),
+        7 => Some(DatePrecision::Tbd),
+        _ => None,
+    }
+}
@@ -0,0 +1,109 @@
+use futures::future::{BoxFuture, FutureExt, Shared};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use crate::domain::error::DomainError;
+
+type Response = Result<Arc<str>, DomainError>;
+type InFlight = Shared<BoxFuture<'static, Response>>;
+
+/// Counters for the health endpoint.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoalescingStats {
+    /// Requests actually sent.
+    pub upstream_calls: u64,
+    /// Requests answered by joining an identical one already in flight.
+    pub coalesced_calls: u64,
+}
+
+/// Lets concurrent identical IGDB queries (same endpoint and body) share one upstream call
+/// and its response. Nothing is kept once the call finishes; caching is left to the providers.
+#[derive(Default)]
+pub struct RequestCoalescer {
+    in_flight: Arc<Mutex<HashMap<(String, String), InFlight>>>,
+    upstream_calls: AtomicU64,
+    coalesced_calls: AtomicU64,
+}
+
+impl RequestCoalescer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Joins the call in flight for `endpoint` and `body`, or starts `call`.
+    pub async fn run<F>(&self, endpoint: &str, body: &str, call: F) -> Response
+    where
+        F: Future<Output = Response> + Send + 'static,
+    {
+        let key = (endpoint.to_string(), body.to_string());
+
+        let request = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            match in_flight.get(&key) {
+                Some(request) => {
+                    self.coalesced_calls.fetch_add(1, Ordering::Relaxed);
+                    request.clone()
+                }
+                None => {
+                    self.upstream_calls.fetch_add(1, Ordering::Relaxed);
+                    let registry = self.in_flight.clone();
+                    let owned_key = key.clone();
+                    // Spawned so the call finishes, and leaves the registry, even if every
+                    // caller waiting on it is cancelled.
+                    let task = tokio::spawn(async move {
+                        let result = call.await;
+                        registry.lock().unwrap().remove(&owned_key);
+                        result
+                    });
+                    let request = async move {
+                        task.await.unwrap_or_else(|e| Err(DomainError::Internal(format!("IGDB request task failed: {}", e))))
+                    }
+                    .boxed()
+                    .shared();
+                    in_flight.insert(key, request.clone());
+                    request
+                }
+            }
+        };
+
+        request.await
+    }
+
+    pub fn stats(&self) -> CoalescingStats {
+        CoalescingStats {
+            upstream_calls: self.upstream_calls.load(Ordering::Relaxed),
+            coalesced_calls: self.coalesced_calls.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn identical_requests_in_flight_share_one_call() {
+        let coalescer = RequestCoalescer::new();
+        let slow = |text: &'static str| async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok(Arc::from(text))
+        };
+
+        let (a, b, other) = tokio::join!(
+            coalescer.run("games", "where id = 1;", slow("doom")),
+            coalescer.run("games", "where id = 1;", slow("never sent")),
+            coalescer.run("games", "where id = 2;", slow("quake")),
+        );
+        assert_eq!(&*a.unwrap(), "doom");
+        assert_eq!(&*b.unwrap(), "doom");
+        assert_eq!(&*other.unwrap(), "quake");
+
+        // Finished calls are not reused.
+        assert_eq!(&*coalescer.run("games", "where id = 1;", slow("doom again")).await.unwrap(), "doom again");
+        let stats = coalescer.stats();
+        assert_eq!((stats.upstream_calls, stats.coalesced_calls), (3, 1));
+    }
+}
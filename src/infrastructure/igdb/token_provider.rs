@@ -0,0 +1,242 @@
+use futures::future::{BoxFuture, FutureExt, Shared};
+use reqwest::Client;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use crate::domain::error::DomainError;
+use crate::infrastructure::igdb::dtos::TwitchTokenResponse;
+
+// A token is not handed out during its last minute, so it can't expire mid-request.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+// The background task renews this long before the token stops being handed out.
+const RENEW_AHEAD: Duration = Duration::from_secs(300);
+const RENEWAL_RETRY_MIN: Duration = Duration::from_secs(1);
+const RENEWAL_RETRY_MAX: Duration = Duration::from_secs(60);
+
+type TokenRequest = Shared<BoxFuture<'static, Result<String, DomainError>>>;
+
+struct AccessToken {
+    value: String,
+    issued_at: Instant,
+    lifetime: Duration,
+}
+
+impl AccessToken {
+    // Both margins shrink for short-lived tokens instead of underflowing.
+    fn usable_until(&self) -> Instant {
+        self.issued_at + self.lifetime.saturating_sub(EXPIRY_MARGIN.min(self.lifetime / 4))
+    }
+
+    fn renew_at(&self) -> Instant {
+        let usable_for = self.usable_until() - self.issued_at;
+        self.usable_until() - RENEW_AHEAD.min(usable_for / 2)
+    }
+}
+
+/// Counters for the health endpoint.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenStatus {
+    pub refreshes: u64,
+    pub failures: u64,
+    pub last_failure: Option<String>,
+    /// Seconds the current token is still handed out for.
+    pub expires_in_seconds: Option<u64>,
+}
+
+/// Twitch `client_credentials` tokens for IGDB. Concurrent callers needing a new token
+/// share a single request, and `spawn_renewal` replaces the token before it runs out.
+pub struct TwitchTokenProvider {
+    http: Client,
+    client_id: String,
+    client_secret: String,
+    auth_url: String,
+    token: RwLock<Option<AccessToken>>,
+    in_flight: Mutex<Option<TokenRequest>>,
+    token_stored: Arc<Notify>,
+    refreshes: AtomicU64,
+    failures: AtomicU64,
+    last_failure: RwLock<Option<String>>,
+}
+
+impl TwitchTokenProvider {
+    pub fn new(http: Client, client_id: String, client_secret: String, auth_url: String) -> Self {
+        Self {
+            http,
+            client_id,
+            client_secret,
+            auth_url,
+            token: RwLock::new(None),
+            in_flight: Mutex::new(None),
+            token_stored: Arc::new(Notify::new()),
+            refreshes: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+            last_failure: RwLock::new(None),
+        }
+    }
+
+    pub async fn get(self: &Arc<Self>) -> Result<String, DomainError> {
+        if let Some(token) = self.usable_token() {
+            return Ok(token);
+        }
+        self.refresh().await
+    }
+
+    /// Drops `rejected` so the next `get` fetches a new token. A token that has already
+    /// replaced it is kept.
+    pub fn invalidate(&self, rejected: &str) {
+        let mut token = self.token.write().unwrap();
+        if token.as_ref().is_some_and(|current| current.value == rejected) {
+            *token = None;
+        }
+    }
+
+    pub fn status(&self) -> TokenStatus {
+        TokenStatus {
+            refreshes: self.refreshes.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            last_failure: self.last_failure.read().unwrap().clone(),
+            expires_in_seconds: self.token.read().unwrap().as_ref()
+                .map(|token| token.usable_until().saturating_duration_since(Instant::now()).as_secs()),
+        }
+    }
+
+    /// Renews the token ahead of expiry once a first one has been fetched. Failed renewals
+    /// are retried with backoff; callers only fetch a token themselves if these keep failing.
+    pub fn spawn_renewal(self: &Arc<Self>) -> JoinHandle<()> {
+        let provider: Weak<Self> = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut retry_delay = RENEWAL_RETRY_MIN;
+            loop {
+                let Some(this) = provider.upgrade() else { return };
+                // Registered before reading the token so a token stored in between isn't missed.
+                let token_stored = this.token_stored.clone();
+                let stored = token_stored.notified();
+                let renew_at = this.token.read().unwrap().as_ref().map(AccessToken::renew_at);
+                drop(this);
+                let Some(renew_at) = renew_at else {
+                    stored.await;
+                    continue;
+                };
+
+                tokio::time::sleep_until(renew_at.into()).await;
+                let Some(this) = provider.upgrade() else { return };
+                // Someone else may have refreshed while we slept.
+                if this.token.read().unwrap().as_ref().is_some_and(|token| token.renew_at() > Instant::now()) {
+                    continue;
+                }
+                match this.refresh().await {
+                    Ok(_) => {
+                        retry_delay = RENEWAL_RETRY_MIN;
+                        // However short-lived the tokens, renewals are at least this far apart.
+                        tokio::time::sleep(RENEWAL_RETRY_MIN).await;
+                    }
+                    Err(e) => {
+                        tracing::warn!("Background Twitch token renewal failed, retrying in {:?}: {}", retry_delay, e);
+                        tokio::time::sleep(retry_delay).await;
+                        retry_delay = (retry_delay * 2).min(RENEWAL_RETRY_MAX);
+                    }
+                }
+            }
+        })
+    }
+
+    fn usable_token(&self) -> Option<String> {
+        self.token.read().unwrap().as_ref()
+            .filter(|token| Instant::now() < token.usable_until())
+            .map(|token| token.value.clone())
+    }
+
+    // Joins the token request in flight, or starts one.
+    async fn refresh(self: &Arc<Self>) -> Result<String, DomainError> {
+        let request = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            match in_flight.as_ref() {
+                Some(request) => request.clone(),
+                None => {
+                    let provider = self.clone();
+                    let request = async move {
+                        let result = provider.fetch().await;
+                        provider.in_flight.lock().unwrap().take();
+                        result
+                    }
+                    .boxed()
+                    .shared();
+                    *in_flight = Some(request.clone());
+                    request
+                }
+            }
+        };
+        request.await
+    }
+
+    async fn fetch(&self) -> Result<String, DomainError> {
+        match self.request_token().await {
+            Ok(response) => {
+                let lifetime = Duration::from_secs(response.expires_in as u64);
+                *self.token.write().unwrap() = Some(AccessToken {
+                    value: response.access_token.clone(),
+                    issued_at: Instant::now(),
+                    lifetime,
+                });
+                self.refreshes.fetch_add(1, Ordering::Relaxed);
+                self.token_stored.notify_waiters();
+                tracing::debug!("Fetched a Twitch token valid for {:?}", lifetime);
+                Ok(response.access_token)
+            }
+            Err(e) => {
+                self.failures.fetch_add(1, Ordering::Relaxed);
+                *self.last_failure.write().unwrap() = Some(e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    async fn request_token(&self) -> Result<TwitchTokenResponse, DomainError> {
+        let params = [
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+            ("grant_type", "client_credentials"),
+        ];
+
+        let response = self.http.post(&self.auth_url)
+            .form(&params)
+            .send()
+            .await
+            .map_err(|e| DomainError::Upstream(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(DomainError::Upstream(format!("Failed to get Twitch token: {}", response.status())));
+        }
+
+        let token: TwitchTokenResponse = response.json().await.map_err(|e| DomainError::Upstream(e.to_string()))?;
+        // Storing a token that is already expired would have the renewal task spin on it.
+        if token.expires_in <= 0 {
+            return Err(DomainError::Upstream(format!("Twitch returned a token with expires_in = {}", token.expires_in)));
+        }
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_lived_tokens_keep_a_usable_window() {
+        let issued_at = Instant::now();
+        let token = |seconds| AccessToken { value: String::new(), issued_at, lifetime: Duration::from_secs(seconds) };
+
+        let long = token(3600);
+        assert_eq!(long.usable_until() - issued_at, Duration::from_secs(3540));
+        assert_eq!(long.renew_at() - issued_at, Duration::from_secs(3240));
+
+        let short = token(20);
+        assert_eq!(short.usable_until() - issued_at, Duration::from_secs(15));
+        assert!(short.renew_at() > issued_at && short.renew_at() < short.usable_until());
+
+        assert_eq!(token(0).usable_until(), issued_at);
+    }
+}
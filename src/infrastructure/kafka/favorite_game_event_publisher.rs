@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use serde_json::to_string;
 use crate::application::ports::output::favorite_game_event_publisher::{FavoriteGameEvent, FavoriteGameEventPublisher};
+use crate::domain::error::DomainError;
 
 // Dummy implementation that logs events instead of sending to Kafka
 pub struct KafkaFavoriteGameEventPublisher {
@@ -17,8 +18,8 @@ impl KafkaFavoriteGameEventPublisher {
 
 #[async_trait]
 impl FavoriteGameEventPublisher for KafkaFavoriteGameEventPublisher {
-    async fn publish_favorite_game_event(&self, event: FavoriteGameEvent) -> Result<(), String> {
-        let payload = to_string(&event).map_err(|e| format!("Serialization error: {}", e))?;
+    async fn publish_favorite_game_event(&self, event: FavoriteGameEvent) -> Result<(), DomainError> {
+        let payload = to_string(&event).map_err(|e| DomainError::Internal(format!("Serialization error: {}", e)))?;
 
         // Just log the event instead of sending to Kafka
         tracing::info!("(MOCK KAFKA) Published to topic '{}': {}", self.topic, payload);
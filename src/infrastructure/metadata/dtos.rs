@@ -0,0 +1,36 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+// Games are keyed by IGDB id so they line up with library entries.
+#[derive(Debug, Deserialize)]
+pub struct MetadataGame {
+    pub id: i64,
+    pub name: String,
+    pub summary: Option<String>,
+    pub storyline: Option<String>,
+    pub release_date: Option<NaiveDate>, // YYYY-MM-DD
+    pub rating: Option<f64>, // 0-10
+    #[serde(default)]
+    pub platforms: Vec<String>,
+    #[serde(default)]
+    pub genres: Vec<String>,
+    #[serde(default)]
+    pub themes: Vec<String>,
+    #[serde(default)]
+    pub game_modes: Vec<String>,
+    #[serde(default)]
+    pub franchises: Vec<String>,
+    #[serde(default)]
+    pub alternative_names: Vec<String>,
+    #[serde(default)]
+    pub similar_game_ids: Vec<i64>,
+    pub time_to_beat: Option<MetadataTimeToBeat>,
+}
+
+// Durations in seconds, like IGDB.
+#[derive(Debug, Deserialize)]
+pub struct MetadataTimeToBeat {
+    pub hastily: Option<i64>,
+    pub normally: Option<i64>,
+    pub completely: Option<i64>,
+}
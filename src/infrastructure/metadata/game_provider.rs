@@ -0,0 +1,130 @@
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::time::Duration;
+use crate::application::ports::output::game_provider::GameProvider;
+use crate::domain::error::DomainError;
+use crate::domain::game::{Game, TimeToBeat};
+use crate::domain::game_filter::GameFilter;
+use crate::domain::page::Page;
+use crate::infrastructure::metadata::dtos::MetadataGame;
+
+/// `GameProvider` over a plain JSON metadata API, used as a secondary source behind IGDB.
+/// The API must answer:
+///
+/// - `GET {base_url}/games/{id}`: one game, or 404
+/// - `GET {base_url}/games?ids=1,2,3`: the known games among those ids
+/// - `GET {base_url}/games?search=text`: games matching a name
+///
+/// Filtering is not part of the contract, so `filter_games` always fails.
+pub struct HttpMetadataGameProvider {
+    client: Client,
+    base_url: String,
+    timeout: Duration,
+}
+
+impl HttpMetadataGameProvider {
+    /// `timeout` overrides the client's request timeout: a fallback source should answer fast.
+    pub fn new(client: Client, base_url: String, timeout: Duration) -> Self {
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            timeout,
+        }
+    }
+
+    // `None` when the API answers 404.
+    async fn get<T: DeserializeOwned>(&self, path: &str, query: &[(&str, String)]) -> Result<Option<T>, DomainError> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self.client.get(&url)
+            .query(query)
+            .timeout(self.timeout)
+            .send()
+            .await
+            .map_err(|e| DomainError::Upstream(format!("Metadata API request to {} failed: {}", url, e)))?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(DomainError::Upstream(format!("Metadata API error at {}: {}", url, response.status())));
+        }
+
+        response.json::<T>()
+            .await
+            .map(Some)
+            .map_err(|e| DomainError::Upstream(format!("Invalid metadata API response from {}: {}", url, e)))
+    }
+}
+
+#[async_trait]
+impl GameProvider for HttpMetadataGameProvider {
+    async fn find_by_external_id(&self, external_id: i64) -> Result<Option<Game>, DomainError> {
+        let game: Option<MetadataGame> = self.get(&format!("/games/{}", external_id), &[]).await?;
+        Ok(game.map(map_metadata_game_to_domain))
+    }
+
+    async fn find_multiple_by_external_ids(&self, external_ids: &[i64]) -> Result<Vec<Game>, DomainError> {
+        if external_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ids = external_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",");
+        let games: Vec<MetadataGame> = self.get("/games", &[("ids", ids)]).await?.unwrap_or_default();
+
+        let mut found: HashMap<i64, Game> = games
+            .into_iter()
+            .map(|game| (game.id, map_metadata_game_to_domain(game)))
+            .collect();
+        Ok(external_ids.iter().filter_map(|id| found.remove(id)).collect())
+    }
+
+    async fn search_by_name(&self, name: &str) -> Result<Vec<Game>, DomainError> {
+        let games: Vec<MetadataGame> = self.get("/games", &[("search", name.to_string())]).await?.unwrap_or_default();
+        Ok(games.into_iter().map(map_metadata_game_to_domain).collect())
+    }
+
+    async fn find_similar_games(&self, external_id: i64) -> Result<Option<Vec<Game>>, DomainError> {
+        match self.find_by_external_id(external_id).await? {
+            Some(game) => Ok(Some(self.find_multiple_by_external_ids(&game.similar_game_ids).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn filter_games(&self, _filter: &GameFilter, _limit: i32, _offset: i32) -> Result<Page<Game>, DomainError> {
+        Err(DomainError::Upstream("The metadata API does not support filtering".to_string()))
+    }
+}
+
+fn map_metadata_game_to_domain(game: MetadataGame) -> Game {
+    Game {
+        id: game.id,
+        name: game.name,
+        summary: game.summary,
+        storyline: game.storyline,
+        release_date: game.release_date,
+        rating: game.rating,
+        cover: None,
+        platforms: game.platforms,
+        genres: game.genres,
+        videos: vec![],
+        screenshots: vec![],
+        artworks: vec![],
+        involved_companies: vec![],
+        franchises: game.franchises,
+        collections: vec![],
+        themes: game.themes,
+        game_modes: game.game_modes,
+        player_perspectives: vec![],
+        age_ratings: vec![],
+        websites: vec![],
+        alternative_names: game.alternative_names,
+        similar_game_ids: game.similar_game_ids,
+        time_to_beat: game.time_to_beat
+            .map(|t| TimeToBeat { hastily: t.hastily, normally: t.normally, completely: t.completely })
+            .filter(|t| !t.is_empty()),
+        sources: vec![],
+        stale: false,
+    }
+}
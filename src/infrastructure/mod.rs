@@ -2,3 +2,7 @@ pub mod web;
 pub mod persistence;
 pub mod igdb;
 pub mod kafka;
+pub mod cache;
+pub mod metadata;
+pub mod composite;
+pub mod http;
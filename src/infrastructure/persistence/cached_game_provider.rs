@@ -0,0 +1,343 @@
+use async_trait::async_trait;
+use futures::future::try_join_all;
+use sqlx::{QueryBuilder, Row, Sqlite, SqlitePool};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use crate::application::ports::output::game_provider::GameProvider;
+use crate::domain::error::DomainError;
+use crate::domain::game::{Game, TimeToBeat};
+use crate::domain::game_filter::GameFilter;
+use crate::domain::page::Page;
+use crate::infrastructure::cache::game_name_index::GameNameIndex;
+use crate::infrastructure::persistence::error::map_db_error;
+
+/// Snapshot of the cache hit/miss counters.
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Expired entries served because the wrapped provider failed.
+    pub stale_hits: u64,
+}
+
+/// `GameProvider` decorator that keeps mapped games in SQLite with a per-entry TTL.
+/// Lookups by id are served locally when possible; only the missing ids reach the
+/// wrapped provider, in batches of at most `batch_size`. When the wrapped provider fails
+/// (e.g. while the IGDB circuit is open), expired entries are served marked as stale.
+///
+/// With a primary source set, games a fallback source served alone are never stored: they
+/// would replace the primary entry with a poorer one for a whole TTL. Lookups by id also
+/// prefer an expired primary entry, marked as stale, over such a game.
+pub struct SqliteCachedGameProvider {
+    inner: Arc<dyn GameProvider>,
+    pool: SqlitePool,
+    ttl: Duration,
+    batch_size: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    stale_hits: AtomicU64,
+    name_index: Option<Arc<GameNameIndex>>,
+    primary_source: Option<String>,
+}
+
+impl SqliteCachedGameProvider {
+    pub fn new(inner: Arc<dyn GameProvider>, pool: SqlitePool, ttl: Duration, batch_size: usize) -> Self {
+        Self {
+            inner,
+            pool,
+            ttl,
+            batch_size: batch_size.max(1),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            stale_hits: AtomicU64::new(0),
+            name_index: None,
+            primary_source: None,
+        }
+    }
+
+    /// Name, as recorded in `Game::sources`, of the source whose games are worth caching.
+    pub fn with_primary_source(mut self, name: &str) -> Self {
+        self.primary_source = Some(name.to_string());
+        self
+    }
+
+    /// Every game that passes through the cache is also added to `name_index`.
+    pub fn with_name_index(mut self, name_index: Arc<GameNameIndex>) -> Self {
+        self.name_index = Some(name_index);
+        self
+    }
+
+    /// Adds every cached game, expired or not, to the name index. Returns how many were indexed.
+    pub async fn index_cached_games(&self) -> Result<usize, DomainError> {
+        let Some(name_index) = &self.name_index else {
+            return Ok(0);
+        };
+
+        let rows = sqlx::query("SELECT payload FROM game_cache")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_db_error)?;
+        let games: Vec<Game> = rows
+            .iter()
+            .filter_map(|row| serde_json::from_str::<Game>(&row.get::<String, _>("payload")).ok())
+            .collect();
+
+        name_index.insert_games(&games);
+        Ok(games.len())
+    }
+
+    pub fn stats(&self) -> GameCacheStats {
+        GameCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            stale_hits: self.stale_hits.load(Ordering::Relaxed),
+        }
+    }
+
+    async fn load_cached(&self, ids: &[i64]) -> Result<HashMap<i64, Game>, String> {
+        self.load(ids, true).await
+    }
+
+    // Expired entries for `ids`, marked as stale, after `error` from the wrapped provider.
+    // Only upstream outages are covered; any other error, or ids never cached, return `error`.
+    async fn load_stale(&self, ids: &[i64], error: DomainError) -> Result<HashMap<i64, Game>, DomainError> {
+        if !matches!(error, DomainError::Upstream(_)) {
+            return Err(error);
+        }
+
+        let stale = self.load_expired(ids).await;
+        if stale.is_empty() {
+            return Err(error);
+        }
+        tracing::warn!("Serving {} stale cached games: {}", stale.len(), error);
+        Ok(stale)
+    }
+
+    // Expired primary data beats a fallback answer: games only a fallback source served are
+    // replaced by their expired cache entries, marked as stale, where there are any.
+    async fn prefer_expired(&self, games: Vec<Game>) -> Vec<Game> {
+        let fallback_ids: Vec<i64> = games.iter().filter(|game| self.is_fallback(game)).map(|game| game.id).collect();
+        if fallback_ids.is_empty() {
+            return games;
+        }
+
+        let mut stale = self.load_expired(&fallback_ids).await;
+        if !stale.is_empty() {
+            tracing::warn!("Serving {} stale cached games instead of fallback answers", stale.len());
+        }
+        games.into_iter().map(|game| stale.remove(&game.id).unwrap_or(game)).collect()
+    }
+
+    // Expired entries for `ids`, marked as stale. A failed read counts as no entries.
+    async fn load_expired(&self, ids: &[i64]) -> HashMap<i64, Game> {
+        let expired = self.load(ids, false).await.unwrap_or_else(|e| {
+            tracing::warn!("Game cache read failed: {}", e);
+            HashMap::new()
+        });
+
+        self.stale_hits.fetch_add(expired.len() as u64, Ordering::Relaxed);
+        expired.into_iter().map(|(id, mut game)| {
+            game.stale = true;
+            (id, game)
+        }).collect()
+    }
+
+    async fn load(&self, ids: &[i64], fresh_only: bool) -> Result<HashMap<i64, Game>, String> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new("SELECT game_id, payload FROM game_cache WHERE game_id IN (");
+        let mut separated = builder.separated(", ");
+        for id in ids {
+            separated.push_bind(*id);
+        }
+        separated.push_unseparated(")");
+        if fresh_only {
+            builder.push(" AND expires_at > ");
+            builder.push_bind(Utc::now().timestamp());
+        }
+
+        let rows = builder.build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut cached = HashMap::with_capacity(rows.len());
+        for row in rows {
+            let game_id: i64 = row.get("game_id");
+            let payload: String = row.get("payload");
+            // An entry written by an older schema of `Game` is treated as a miss.
+            match serde_json::from_str::<Game>(&payload) {
+                Ok(game) => {
+                    cached.insert(game_id, game);
+                }
+                Err(e) => tracing::warn!("Discarding unreadable cache entry for game {}: {}", game_id, e),
+            }
+        }
+
+        Ok(cached)
+    }
+
+    // Whether `game` was served by a source other than the primary one.
+    fn is_fallback(&self, game: &Game) -> bool {
+        self.primary_source.as_ref()
+            .is_some_and(|primary| !game.sources.is_empty() && !game.sources.contains(primary))
+    }
+
+    async fn store(&self, games: &[Game]) -> Result<(), String> {
+        let games: Vec<&Game> = games.iter().filter(|game| !self.is_fallback(game)).collect();
+        if games.is_empty() {
+            return Ok(());
+        }
+
+        let cached_at = Utc::now().timestamp();
+        let expires_at = cached_at + self.ttl.as_secs() as i64;
+
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        for game in games {
+            let payload = serde_json::to_string(game).map_err(|e| e.to_string())?;
+            // List lookups don't fetch time-to-beat, so they keep the one a detail lookup cached.
+            sqlx::query(
+                "INSERT INTO game_cache (game_id, payload, cached_at, expires_at) VALUES ($1, $2, $3, $4) \
+                 ON CONFLICT (game_id) DO UPDATE SET payload = CASE \
+                     WHEN json_extract(excluded.payload, '$.time_to_beat') IS NULL \
+                      AND json_extract(game_cache.payload, '$.time_to_beat') IS NOT NULL \
+                     THEN json_set(excluded.payload, '$.time_to_beat', json(json_extract(game_cache.payload, '$.time_to_beat'))) \
+                     ELSE excluded.payload END, \
+                 cached_at = excluded.cached_at, expires_at = excluded.expires_at"
+            )
+            .bind(game.id)
+            .bind(payload)
+            .bind(cached_at)
+            .bind(expires_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        }
+        tx.commit().await.map_err(|e| e.to_string())
+    }
+
+    // Adds time-to-beat to a cached game without extending its expiry. A game without data
+    // gets an empty one, so later hits don't ask again.
+    async fn complete_time_to_beat(&self, game: &mut Game) {
+        let time_to_beat = match self.inner.find_time_to_beat(&[game.id]).await {
+            Ok(mut found) => found.remove(&game.id).unwrap_or_default(),
+            Err(e) => {
+                tracing::warn!("Could not fetch time-to-beat for cached game {}: {}", game.id, e);
+                return;
+            }
+        };
+        game.time_to_beat = Some(time_to_beat);
+
+        let result = match serde_json::to_string(game) {
+            Ok(payload) => sqlx::query("UPDATE game_cache SET payload = $1 WHERE game_id = $2")
+                .bind(payload)
+                .bind(game.id)
+                .execute(&self.pool)
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(e) = result {
+            tracing::warn!("Failed to update cached game {}: {}", game.id, e);
+        }
+    }
+
+    // Cache failures must never fail the request, so they are only logged.
+    async fn store_quietly(&self, games: &[Game]) {
+        if let Some(name_index) = &self.name_index {
+            name_index.insert_games(games);
+        }
+        if let Err(e) = self.store(games).await {
+            tracing::warn!("Failed to write {} games to the cache: {}", games.len(), e);
+        }
+    }
+}
+
+#[async_trait]
+impl GameProvider for SqliteCachedGameProvider {
+    async fn find_by_external_id(&self, external_id: i64) -> Result<Option<Game>, DomainError> {
+        let cached = self.load_cached(&[external_id]).await.unwrap_or_else(|e| {
+            tracing::warn!("Game cache read failed: {}", e);
+            HashMap::new()
+        });
+
+        if let Some(mut game) = cached.into_values().next() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            // Games cached by a list lookup lack the time-to-beat only details fetch.
+            if game.time_to_beat.is_none() {
+                self.complete_time_to_beat(&mut game).await;
+            }
+            return Ok(Some(game));
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let game = match self.inner.find_by_external_id(external_id).await {
+            Ok(game) => game,
+            Err(e) => return Ok(self.load_stale(&[external_id], e).await?.into_values().next()),
+        };
+        let Some(game) = game else {
+            return Ok(None);
+        };
+        self.store_quietly(std::slice::from_ref(&game)).await;
+        Ok(self.prefer_expired(vec![game]).await.pop())
+    }
+
+    async fn find_multiple_by_external_ids(&self, external_ids: &[i64]) -> Result<Vec<Game>, DomainError> {
+        let mut seen = HashSet::new();
+        let unique_ids: Vec<i64> = external_ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+
+        let mut found = self.load_cached(&unique_ids).await.unwrap_or_else(|e| {
+            tracing::warn!("Game cache read failed: {}", e);
+            HashMap::new()
+        });
+
+        let missing_ids: Vec<i64> = unique_ids.iter().copied().filter(|id| !found.contains_key(id)).collect();
+        self.hits.fetch_add(found.len() as u64, Ordering::Relaxed);
+        self.misses.fetch_add(missing_ids.len() as u64, Ordering::Relaxed);
+
+        let fetches = missing_ids.chunks(self.batch_size).map(|chunk| self.inner.find_multiple_by_external_ids(chunk));
+        match try_join_all(fetches).await {
+            Ok(fetched) => {
+                let fetched: Vec<Game> = fetched.into_iter().flatten().collect();
+                self.store_quietly(&fetched).await;
+                let fetched = self.prefer_expired(fetched).await;
+                found.extend(fetched.into_iter().map(|game| (game.id, game)));
+            }
+            Err(e) => found.extend(self.load_stale(&missing_ids, e).await?),
+        }
+
+        Ok(unique_ids.iter().filter_map(|id| found.remove(id)).collect())
+    }
+
+    async fn search_by_name(&self, name: &str) -> Result<Vec<Game>, DomainError> {
+        let games = self.inner.search_by_name(name).await?;
+        self.store_quietly(&games).await;
+        Ok(games)
+    }
+
+    // Both lookups go through the cache, so a warm detail screen costs no IGDB calls.
+    async fn find_similar_games(&self, external_id: i64) -> Result<Option<Vec<Game>>, DomainError> {
+        match self.find_by_external_id(external_id).await? {
+            Some(game) => Ok(Some(self.find_multiple_by_external_ids(&game.similar_game_ids).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn filter_games(&self, filter: &GameFilter, limit: i32, offset: i32) -> Result<Page<Game>, DomainError> {
+        let page = self.inner.filter_games(filter, limit, offset).await?;
+        self.store_quietly(&page.content).await;
+        Ok(page)
+    }
+
+    // Cached games may lack it, so this always asks the wrapped provider.
+    async fn find_time_to_beat(&self, external_ids: &[i64]) -> Result<HashMap<i64, TimeToBeat>, DomainError> {
+        self.inner.find_time_to_beat(external_ids).await
+    }
+}
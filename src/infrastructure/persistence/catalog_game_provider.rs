@@ -0,0 +1,178 @@
+use async_trait::async_trait;
+use sqlx::{QueryBuilder, Row, Sqlite, SqlitePool};
+use std::collections::HashMap;
+use crate::application::ports::output::game_provider::GameProvider;
+use crate::application::ports::output::game_suggestion_provider::GameSuggestionProvider;
+use crate::domain::error::DomainError;
+use crate::domain::game::Game;
+use crate::domain::game_filter::{GameFilter, GameSortField, SortDirection};
+use crate::domain::game_suggestion::GameSuggestion;
+use crate::domain::page::Page;
+use crate::infrastructure::persistence::error::map_db_error;
+
+const SEARCH_LIMIT: i64 = 20;
+
+/// `GameProvider` that answers every lookup from the local catalog tables filled by the
+/// catalog sync job, without calling the external provider. Games that have not been
+/// synced yet are reported as not found.
+pub struct SqliteCatalogGameProvider {
+    pool: SqlitePool,
+}
+
+impl SqliteCatalogGameProvider {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn search(&self, name: &str, limit: i64) -> Result<Vec<Game>, DomainError> {
+        // Prefix matches first, then the best rated.
+        let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new("SELECT payload FROM catalog_games WHERE name LIKE ");
+        builder.push_bind(like_pattern(name, false));
+        builder.push(" ESCAPE '\\' ORDER BY CASE WHEN name LIKE ");
+        builder.push_bind(like_pattern(name, true));
+        builder.push(" ESCAPE '\\' THEN 0 ELSE 1 END, rating DESC, game_id LIMIT ");
+        builder.push_bind(limit);
+
+        self.fetch_games(builder).await
+    }
+
+    async fn fetch_games(&self, mut builder: QueryBuilder<'_, Sqlite>) -> Result<Vec<Game>, DomainError> {
+        let rows = builder.build().fetch_all(&self.pool).await.map_err(map_db_error)?;
+
+        rows.iter()
+            .map(|row| {
+                let payload: String = row.get("payload");
+                serde_json::from_str::<Game>(&payload)
+                    .map_err(|e| DomainError::Internal(format!("Unreadable catalog entry: {}", e)))
+            })
+            .collect()
+    }
+}
+
+// `LIKE` pattern matching `text` literally; `%`, `_` and the escape character are escaped.
+fn like_pattern(text: &str, prefix_only: bool) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    if prefix_only {
+        format!("{}%", escaped)
+    } else {
+        format!("%{}%", escaped)
+    }
+}
+
+fn push_filter(builder: &mut QueryBuilder<'_, Sqlite>, filter: &GameFilter) {
+    builder.push(" WHERE 1 = 1");
+
+    if !filter.platform_ids.is_empty() {
+        builder.push(" AND EXISTS (SELECT 1 FROM catalog_game_platforms p WHERE p.game_id = g.game_id AND p.platform_id IN (");
+        let mut separated = builder.separated(", ");
+        for id in &filter.platform_ids {
+            separated.push_bind(*id);
+        }
+        builder.push("))");
+    }
+    if !filter.genres.is_empty() {
+        builder.push(" AND EXISTS (SELECT 1 FROM catalog_game_genres ge WHERE ge.game_id = g.game_id AND ge.genre IN (");
+        let mut separated = builder.separated(", ");
+        for genre in &filter.genres {
+            separated.push_bind(genre.clone());
+        }
+        builder.push("))");
+    }
+    // Release dates are stored as ISO dates, so they compare correctly as text.
+    if let Some(from) = filter.release_year_from {
+        builder.push(" AND g.release_date >= ").push_bind(format!("{:04}-01-01", from));
+    }
+    if let Some(to) = filter.release_year_to {
+        builder.push(" AND g.release_date < ").push_bind(format!("{:04}-01-01", to + 1));
+    }
+    if let Some(min) = filter.rating_min {
+        builder.push(" AND g.rating >= ").push_bind(min);
+    }
+    if let Some(max) = filter.rating_max {
+        builder.push(" AND g.rating <= ").push_bind(max);
+    }
+    if let Some(name) = &filter.name_contains {
+        builder.push(" AND g.name LIKE ").push_bind(like_pattern(name, false)).push(" ESCAPE '\\'");
+    }
+}
+
+#[async_trait]
+impl GameProvider for SqliteCatalogGameProvider {
+    async fn find_by_external_id(&self, external_id: i64) -> Result<Option<Game>, DomainError> {
+        let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new("SELECT payload FROM catalog_games WHERE game_id = ");
+        builder.push_bind(external_id);
+
+        Ok(self.fetch_games(builder).await?.into_iter().next())
+    }
+
+    async fn find_multiple_by_external_ids(&self, external_ids: &[i64]) -> Result<Vec<Game>, DomainError> {
+        if external_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new("SELECT payload FROM catalog_games WHERE game_id IN (");
+        let mut separated = builder.separated(", ");
+        for id in external_ids {
+            separated.push_bind(*id);
+        }
+        separated.push_unseparated(")");
+
+        let mut found: HashMap<i64, Game> = self.fetch_games(builder).await?
+            .into_iter()
+            .map(|game| (game.id, game))
+            .collect();
+
+        Ok(external_ids.iter().filter_map(|id| found.remove(id)).collect())
+    }
+
+    async fn search_by_name(&self, name: &str) -> Result<Vec<Game>, DomainError> {
+        self.search(name, SEARCH_LIMIT).await
+    }
+
+    // Similar games that have not been synced yet are left out.
+    async fn find_similar_games(&self, external_id: i64) -> Result<Option<Vec<Game>>, DomainError> {
+        match self.find_by_external_id(external_id).await? {
+            Some(game) => Ok(Some(self.find_multiple_by_external_ids(&game.similar_game_ids).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn filter_games(&self, filter: &GameFilter, limit: i32, offset: i32) -> Result<Page<Game>, DomainError> {
+        let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new("SELECT g.payload FROM catalog_games g");
+        push_filter(&mut builder, filter);
+
+        builder.push(" ORDER BY ");
+        if let Some(sort) = filter.sort {
+            builder.push(match sort.field {
+                GameSortField::Name => "g.name COLLATE NOCASE",
+                GameSortField::ReleaseDate => "g.release_date",
+                GameSortField::Rating => "g.rating",
+            });
+            builder.push(match sort.direction {
+                SortDirection::Asc => " ASC, ",
+                SortDirection::Desc => " DESC, ",
+            });
+        }
+        builder.push("g.game_id LIMIT ").push_bind(limit.max(0)).push(" OFFSET ").push_bind(offset.max(0));
+        let games = self.fetch_games(builder).await?;
+
+        let mut count_builder: QueryBuilder<Sqlite> = QueryBuilder::new("SELECT COUNT(*) AS total FROM catalog_games g");
+        push_filter(&mut count_builder, filter);
+        let total_elements: i64 = count_builder.build()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_db_error)?
+            .get("total");
+
+        let page = if limit > 0 { offset / limit } else { 0 };
+        Ok(Page::new(games, page, limit, total_elements))
+    }
+}
+
+#[async_trait]
+impl GameSuggestionProvider for SqliteCatalogGameProvider {
+    async fn suggest(&self, query: &str, limit: usize) -> Result<Vec<GameSuggestion>, DomainError> {
+        let games = self.search(query, limit as i64).await?;
+        Ok(games.iter().map(GameSuggestion::from).collect())
+    }
+}
@@ -0,0 +1,12 @@
+use crate::domain::error::DomainError;
+
+// Translates SQLx failures into domain errors so constraint violations surface as conflicts.
+pub fn map_db_error(error: sqlx::Error) -> DomainError {
+    match &error {
+        sqlx::Error::RowNotFound => DomainError::NotFound("Record not found".to_string()),
+        sqlx::Error::Database(db_error) if db_error.is_unique_violation() => {
+            DomainError::Conflict(db_error.message().to_string())
+        }
+        _ => DomainError::Internal(error.to_string()),
+    }
+}
@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Row, Sqlite, SqlitePool, Transaction};
+use crate::application::ports::output::game_catalog_repository::GameCatalogRepository;
+use crate::domain::catalog::{CatalogGame, CatalogSyncProgress, SyncCursor, SyncStatus};
+use crate::domain::error::DomainError;
+use crate::infrastructure::persistence::error::map_db_error;
+
+pub struct SqliteGameCatalogRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteGameCatalogRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl GameCatalogRepository for SqliteGameCatalogRepository {
+    async fn load_progress(&self) -> Result<CatalogSyncProgress, DomainError> {
+        let row = sqlx::query(
+            "SELECT s.*, (SELECT COUNT(*) FROM catalog_games) AS total_games FROM catalog_sync_state s WHERE s.id = 1"
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_db_error)?;
+
+        let status: String = row.get("status");
+        let run_id: Option<String> = row.get("run_id");
+        Ok(CatalogSyncProgress {
+            status: parse_status(&status),
+            run_id: run_id.and_then(|id| id.parse().ok()),
+            cursor: SyncCursor {
+                updated_at: row.get("cursor_updated_at"),
+                game_id: row.get("cursor_game_id"),
+            },
+            synced_games: row.get("synced_games"),
+            remaining_games: row.get("remaining_games"),
+            total_games: row.get("total_games"),
+            started_at: row.get("started_at"),
+            finished_at: row.get("finished_at"),
+            last_error: row.get("last_error"),
+        })
+    }
+
+    async fn try_start_run(&self, progress: &CatalogSyncProgress, stale_before: DateTime<Utc>) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            "UPDATE catalog_sync_state SET status = $1, cursor_updated_at = $2, cursor_game_id = $3, synced_games = $4, \
+             remaining_games = $5, started_at = $6, finished_at = $7, last_error = $8, run_id = $9, heartbeat_at = $10 \
+             WHERE id = 1 AND (status != 'RUNNING' OR heartbeat_at IS NULL OR heartbeat_at < $11)"
+        )
+        .bind(format_status(progress.status))
+        .bind(progress.cursor.updated_at)
+        .bind(progress.cursor.game_id)
+        .bind(progress.synced_games)
+        .bind(progress.remaining_games)
+        .bind(progress.started_at)
+        .bind(progress.finished_at)
+        .bind(&progress.last_error)
+        .bind(progress.run_id.map(|id| id.to_string()))
+        .bind(Utc::now().timestamp())
+        .bind(stale_before.timestamp())
+        .execute(&self.pool)
+        .await
+        .map_err(map_db_error)?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn save_progress(&self, progress: &CatalogSyncProgress) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await.map_err(map_db_error)?;
+        write_progress(&mut tx, progress).await?;
+        tx.commit().await.map_err(map_db_error)
+    }
+
+    async fn save_page(&self, games: &[CatalogGame], progress: &CatalogSyncProgress) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await.map_err(map_db_error)?;
+
+        for entry in games {
+            let game = &entry.game;
+            let payload = serde_json::to_string(game).map_err(|e| DomainError::Internal(e.to_string()))?;
+
+            sqlx::query(
+                "INSERT INTO catalog_games (game_id, name, release_date, rating, updated_at, payload) VALUES ($1, $2, $3, $4, $5, $6) \
+                 ON CONFLICT (game_id) DO UPDATE SET name = excluded.name, release_date = excluded.release_date, \
+                 rating = excluded.rating, updated_at = excluded.updated_at, payload = excluded.payload"
+            )
+            .bind(game.id)
+            .bind(&game.name)
+            .bind(game.release_date.map(|d| d.to_string()))
+            .bind(game.rating)
+            .bind(entry.updated_at)
+            .bind(payload)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_db_error)?;
+
+            sqlx::query("DELETE FROM catalog_game_platforms WHERE game_id = $1")
+                .bind(game.id)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_db_error)?;
+            for platform_id in &entry.platform_ids {
+                sqlx::query("INSERT OR IGNORE INTO catalog_game_platforms (game_id, platform_id) VALUES ($1, $2)")
+                    .bind(game.id)
+                    .bind(platform_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(map_db_error)?;
+            }
+
+            sqlx::query("DELETE FROM catalog_game_genres WHERE game_id = $1")
+                .bind(game.id)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_db_error)?;
+            for genre in &game.genres {
+                sqlx::query("INSERT OR IGNORE INTO catalog_game_genres (game_id, genre) VALUES ($1, $2)")
+                    .bind(game.id)
+                    .bind(genre)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(map_db_error)?;
+            }
+        }
+
+        write_progress(&mut tx, progress).await?;
+        tx.commit().await.map_err(map_db_error)
+    }
+}
+
+async fn write_progress(tx: &mut Transaction<'_, Sqlite>, progress: &CatalogSyncProgress) -> Result<(), DomainError> {
+    let result = sqlx::query(
+        "UPDATE catalog_sync_state SET status = $1, cursor_updated_at = $2, cursor_game_id = $3, synced_games = $4, \
+         remaining_games = $5, started_at = $6, finished_at = $7, last_error = $8, heartbeat_at = $9 \
+         WHERE id = 1 AND run_id IS $10"
+    )
+    .bind(format_status(progress.status))
+    .bind(progress.cursor.updated_at)
+    .bind(progress.cursor.game_id)
+    .bind(progress.synced_games)
+    .bind(progress.remaining_games)
+    .bind(progress.started_at)
+    .bind(progress.finished_at)
+    .bind(&progress.last_error)
+    .bind(Utc::now().timestamp())
+    .bind(progress.run_id.map(|id| id.to_string()))
+    .execute(&mut **tx)
+    .await
+    .map_err(map_db_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(DomainError::Conflict("Another catalog sync run has taken over".to_string()));
+    }
+    Ok(())
+}
+
+fn format_status(status: SyncStatus) -> &'static str {
+    match status {
+        SyncStatus::Idle => "IDLE",
+        SyncStatus::Running => "RUNNING",
+        SyncStatus::Completed => "COMPLETED",
+        SyncStatus::Failed => "FAILED",
+    }
+}
+
+fn parse_status(status: &str) -> SyncStatus {
+    match status {
+        "RUNNING" => SyncStatus::Running,
+        "COMPLETED" => SyncStatus::Completed,
+        "FAILED" => SyncStatus::Failed,
+        _ => SyncStatus::Idle,
+    }
+}
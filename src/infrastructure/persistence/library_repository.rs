@@ -4,6 +4,8 @@ use uuid::Uuid;
 use crate::application::ports::output::library_repository::LibraryRepository;
 use crate::domain::user::{UserGame, GameStatus};
 use crate::domain::page::Page;
+use crate::domain::error::DomainError;
+use crate::infrastructure::persistence::error::map_db_error;
 
 pub struct SqliteLibraryRepository {
     pool: SqlitePool,
@@ -17,9 +19,9 @@ impl SqliteLibraryRepository {
 
 #[async_trait]
 impl LibraryRepository for SqliteLibraryRepository {
-    async fn save(&self, user_game: &UserGame) -> Result<UserGame, String> {
-        let user_id = Uuid::parse_str(&user_game.user_id).map_err(|e| e.to_string())?;
-        let status_str = format!("{:?}", user_game.status).to_uppercase();
+    async fn save(&self, user_game: &UserGame) -> Result<UserGame, DomainError> {
+        let user_id = Uuid::parse_str(&user_game.user_id).map_err(|e| DomainError::Validation(e.to_string()))?;
+        let status_str = status_to_str(user_game.status);
 
         sqlx::query(
             "INSERT INTO user_games (user_id, game_id, status, added_at, is_favorite) VALUES ($1, $2, $3, $4, $5) RETURNING *"
@@ -32,33 +34,33 @@ impl LibraryRepository for SqliteLibraryRepository {
         .fetch_one(&self.pool)
         .await
         .map(|row| map_row(&row))
-        .map_err(|e| e.to_string())
+        .map_err(map_db_error)
     }
 
-    async fn find_by_user_id_and_game_id(&self, user_id: Uuid, game_id: i64) -> Result<Option<UserGame>, String> {
+    async fn find_by_user_id_and_game_id(&self, user_id: Uuid, game_id: i64) -> Result<Option<UserGame>, DomainError> {
         let result = sqlx::query("SELECT * FROM user_games WHERE user_id = $1 AND game_id = $2")
             .bind(user_id)
             .bind(game_id)
             .fetch_optional(&self.pool)
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(map_db_error)?;
 
         Ok(result.map(|row| map_row(&row)))
     }
 
-    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<UserGame>, String> {
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<UserGame>, DomainError> {
         let rows = sqlx::query("SELECT * FROM user_games WHERE user_id = $1")
             .bind(user_id)
             .fetch_all(&self.pool)
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(map_db_error)?;
 
         Ok(rows.iter().map(map_row).collect())
     }
 
-    async fn update(&self, user_game: &UserGame) -> Result<UserGame, String> {
-        let user_id = Uuid::parse_str(&user_game.user_id).map_err(|e| e.to_string())?;
-        let status_str = format!("{:?}", user_game.status).to_uppercase();
+    async fn update(&self, user_game: &UserGame) -> Result<UserGame, DomainError> {
+        let user_id = Uuid::parse_str(&user_game.user_id).map_err(|e| DomainError::Validation(e.to_string()))?;
+        let status_str = status_to_str(user_game.status);
 
         sqlx::query(
             "UPDATE user_games SET status = $1, added_at = $2, is_favorite = $3 WHERE user_id = $4 AND game_id = $5 RETURNING *"
@@ -71,20 +73,20 @@ impl LibraryRepository for SqliteLibraryRepository {
         .fetch_one(&self.pool)
         .await
         .map(|row| map_row(&row))
-        .map_err(|e| e.to_string())
+        .map_err(map_db_error)
     }
 
-    async fn delete_by_user_id_and_game_id(&self, user_id: Uuid, game_id: i64) -> Result<(), String> {
+    async fn delete_by_user_id_and_game_id(&self, user_id: Uuid, game_id: i64) -> Result<(), DomainError> {
         sqlx::query("DELETE FROM user_games WHERE user_id = $1 AND game_id = $2")
             .bind(user_id)
             .bind(game_id)
             .execute(&self.pool)
             .await
             .map(|_| ())
-            .map_err(|e| e.to_string())
+            .map_err(map_db_error)
     }
 
-    async fn find_by_user_id_and_is_favorite_true(&self, user_id: Uuid, page: i32, size: i32) -> Result<Page<UserGame>, String> {
+    async fn find_by_user_id_and_is_favorite_true(&self, user_id: Uuid, page: i32, size: i32) -> Result<Page<UserGame>, DomainError> {
         let offset = page * size;
 
         // 1. Get content
@@ -94,7 +96,7 @@ impl LibraryRepository for SqliteLibraryRepository {
             .bind(offset)
             .fetch_all(&self.pool)
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(map_db_error)?;
 
         let user_games: Vec<UserGame> = rows.iter().map(map_row).collect();
 
@@ -103,7 +105,7 @@ impl LibraryRepository for SqliteLibraryRepository {
             .bind(user_id)
             .fetch_one(&self.pool)
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(map_db_error)?;
 
         let total_elements: i64 = count_row.get("count");
 
@@ -122,6 +124,16 @@ fn map_row(row: &SqliteRow) -> UserGame {
     }
 }
 
+// Must stay in sync with `parse_status`.
+fn status_to_str(status: GameStatus) -> &'static str {
+    match status {
+        GameStatus::None => "NONE",
+        GameStatus::WantToPlay => "WANT_TO_PLAY",
+        GameStatus::Playing => "PLAYING",
+        GameStatus::Completed => "COMPLETED",
+    }
+}
+
 fn parse_status(status_str: &str) -> GameStatus {
     match status_str {
         "NONE" => GameStatus::None,
@@ -1,2 +1,6 @@
 pub mod user_repository;
 pub mod library_repository;
+pub mod cached_game_provider;
+pub mod catalog_game_provider;
+pub mod game_catalog_repository;
+pub mod error;
@@ -3,6 +3,8 @@ use sqlx::{SqlitePool, Row};
 use uuid::Uuid;
 use crate::application::ports::output::user_repository::UserRepository;
 use crate::domain::user::User;
+use crate::domain::error::DomainError;
+use crate::infrastructure::persistence::error::map_db_error;
 
 pub struct SqliteUserRepository {
     pool: SqlitePool,
@@ -16,8 +18,8 @@ impl SqliteUserRepository {
 
 #[async_trait]
 impl UserRepository for SqliteUserRepository {
-    async fn save(&self, user: &User) -> Result<User, String> {
-        let user_id = Uuid::parse_str(&user.id).map_err(|e| e.to_string())?;
+    async fn save(&self, user: &User) -> Result<User, DomainError> {
+        let user_id = Uuid::parse_str(&user.id).map_err(|e| DomainError::Validation(e.to_string()))?;
 
         sqlx::query(
             "INSERT INTO users (id, username, email, password) VALUES ($1, $2, $3, $4) RETURNING *"
@@ -34,15 +36,15 @@ impl UserRepository for SqliteUserRepository {
             email: row.get("email"),
             password: row.get("password"),
         })
-        .map_err(|e| e.to_string())
+        .map_err(map_db_error)
     }
 
-    async fn find_by_email(&self, email: &str) -> Result<Option<User>, String> {
+    async fn find_by_email(&self, email: &str) -> Result<Option<User>, DomainError> {
         let result = sqlx::query("SELECT * FROM users WHERE email = $1")
             .bind(email)
             .fetch_optional(&self.pool)
             .await
-            .map_err(|e| e.to_string())?;
+            .map_err(map_db_error)?;
 
         match result {
             Some(row) => Ok(Some(User {
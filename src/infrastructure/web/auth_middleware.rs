@@ -1,7 +1,7 @@
 use axum::{
     async_trait,
     extract::FromRequestParts,
-    http::{request::Parts, StatusCode},
+    http::{header::AUTHORIZATION, request::Parts, StatusCode},
     response::{IntoResponse, Response},
     Json, RequestPartsExt,
 };
@@ -44,6 +44,30 @@ where
     }
 }
 
+/// Like `AuthUser`, but lets anonymous requests through. A token that is present
+/// but invalid is still rejected.
+pub struct MaybeAuthUser(pub Option<Claims>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for MaybeAuthUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if !parts.headers.contains_key(AUTHORIZATION) {
+            return Ok(MaybeAuthUser(None));
+        }
+
+        let AuthUser(claims) = AuthUser::from_request_parts(parts, state).await.map_err(|e| match e {
+            AuthError::MissingCredentials => AuthError::InvalidToken,
+            other => other,
+        })?;
+        Ok(MaybeAuthUser(Some(claims)))
+    }
+}
+
 #[derive(Debug)]
 pub enum AuthError {
     #[allow(dead_code)]
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::catalog::SyncStatus;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SyncCursorDTO {
+    #[serde(rename = "updatedAt")]
+    pub updated_at: i64,
+    #[serde(rename = "gameId")]
+    pub game_id: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CatalogSyncProgressDTO {
+    pub status: SyncStatus,
+    pub cursor: SyncCursorDTO,
+    #[serde(rename = "syncedGames")]
+    pub synced_games: i64,
+    #[serde(rename = "remainingGames")]
+    pub remaining_games: Option<i64>,
+    #[serde(rename = "percentComplete")]
+    pub percent_complete: Option<f64>,
+    #[serde(rename = "totalGames")]
+    pub total_games: i64,
+    #[serde(rename = "startedAt")]
+    pub started_at: Option<String>,
+    #[serde(rename = "finishedAt")]
+    pub finished_at: Option<String>,
+    #[serde(rename = "lastError")]
+    pub last_error: Option<String>,
+}
@@ -15,3 +15,15 @@ pub struct PageableDTO {
     pub page_size: i32,
     pub sort: SortDTO,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldErrorDTO {
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImageSizeQueryDTO {
+    #[serde(rename = "imageSize")]
+    pub image_size: Option<String>,
+}
@@ -11,6 +11,44 @@ pub struct GameDTO {
     #[serde(rename = "coverImageUrl")]
     pub cover_image_url: Option<String>,
     pub platforms: Vec<String>,
+    pub sources: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GameBatchResponseDTO {
+    pub games: Vec<GameDTO>,
+    #[serde(rename = "missingIds")]
+    pub missing_ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GameSuggestionDTO {
+    pub id: i64,
+    pub name: String,
+    #[serde(rename = "releaseYear")]
+    pub release_year: Option<i32>,
+    #[serde(rename = "coverThumbUrl")]
+    pub cover_thumb_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AutocompleteQueryDTO {
+    pub q: Option<String>,
+    pub limit: Option<usize>,
+    #[serde(rename = "imageSize")]
+    pub image_size: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImageDTO {
+    pub id: i64,
+    pub alpha_channel: bool,
+    pub animated: bool,
+    pub checksum: Option<String>,
+    pub height: Option<i32>,
+    pub image_id: String,
+    pub url: String,
+    pub width: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,13 +56,13 @@ pub struct ArtworkDTO {
     pub id: i64,
     pub alpha_channel: bool,
     pub animated: bool,
-    pub artwork_type: i64,
-    pub checksum: String,
+    pub artwork_type: Option<i64>,
+    pub checksum: Option<String>,
     pub game: i64,
-    pub height: i32,
+    pub height: Option<i32>,
     pub image_id: String,
     pub url: String,
-    pub width: i32,
+    pub width: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -35,18 +73,77 @@ pub struct GameSummaryDTO {
     pub storyline: Option<String>,
     pub genres: Vec<String>,
     pub videos: Vec<String>,
-    pub screenshots: Vec<String>,
+    pub cover: Option<ImageDTO>,
+    pub screenshots: Vec<ImageDTO>,
     pub artworks: Vec<ArtworkDTO>,
+    pub developers: Vec<String>,
+    pub publishers: Vec<String>,
+    pub franchises: Vec<String>,
+    pub collections: Vec<String>,
+    pub themes: Vec<String>,
+    #[serde(rename = "gameModes")]
+    pub game_modes: Vec<String>,
+    #[serde(rename = "playerPerspectives")]
+    pub player_perspectives: Vec<String>,
+    #[serde(rename = "ageRatings")]
+    pub age_ratings: Vec<AgeRatingDTO>,
+    pub websites: Vec<WebsiteDTO>,
+    #[serde(rename = "alternativeNames")]
+    pub alternative_names: Vec<String>,
+    #[serde(rename = "timeToBeat")]
+    pub time_to_beat: Option<TimeToBeatDTO>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimeToBeatDTO {
+    #[serde(rename = "hastilyHours")]
+    pub hastily_hours: Option<f64>,
+    #[serde(rename = "normallyHours")]
+    pub normally_hours: Option<f64>,
+    #[serde(rename = "completelyHours")]
+    pub completely_hours: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AgeRatingDTO {
+    pub organization: String,
+    pub rating: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+pub struct WebsiteDTO {
+    pub url: String,
+    pub category: Option<String>,
+    pub trusted: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct GameFilterRequestDTO {
-    pub filter: String,
-    pub sort: Option<String>,
+    #[serde(rename = "platformIds", default)]
+    pub platform_ids: Vec<i64>,
+    #[serde(default)]
+    pub genres: Vec<String>,
+    #[serde(rename = "releaseYearFrom")]
+    pub release_year_from: Option<i32>,
+    #[serde(rename = "releaseYearTo")]
+    pub release_year_to: Option<i32>,
+    #[serde(rename = "ratingMin")]
+    pub rating_min: Option<f64>,
+    #[serde(rename = "ratingMax")]
+    pub rating_max: Option<f64>,
+    #[serde(rename = "nameContains")]
+    pub name_contains: Option<String>,
+    pub sort: Option<GameSortDTO>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GameSortDTO {
+    pub field: String,
+    pub direction: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GamePageDTO {
     pub content: Vec<GameDTO>,
@@ -2,3 +2,6 @@ pub mod common_dtos;
 pub mod game_dtos;
 pub mod platform_dtos;
 pub mod user_dtos;
+pub mod reference_data_dtos;
+pub mod catalog_dtos;
+pub mod release_dtos;
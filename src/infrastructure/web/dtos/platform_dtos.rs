@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 use crate::domain::platform::PlatformType;
+use crate::infrastructure::web::dtos::common_dtos::{PageableDTO, SortDTO};
+use crate::infrastructure::web::dtos::game_dtos::ImageDTO;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PlatformDTO {
@@ -8,4 +10,63 @@ pub struct PlatformDTO {
     pub generation: Option<i32>,
     #[serde(rename = "platformType")]
     pub platform_type: PlatformType,
+    pub abbreviation: Option<String>,
+    pub family: Option<PlatformFamilyDTO>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlatformFamilyDTO {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlatformDetailDTO {
+    #[serde(flatten)]
+    pub platform: PlatformDTO,
+    pub logo: Option<ImageDTO>,
+    pub versions: Vec<PlatformVersionDTO>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlatformVersionDTO {
+    pub id: i64,
+    pub name: String,
+    #[serde(rename = "releaseDates")]
+    pub release_dates: Vec<PlatformReleaseDateDTO>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlatformReleaseDateDTO {
+    pub date: Option<String>,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlatformFilterQueryDTO {
+    // Comma-separated, e.g. `CONSOLE,PORTABLE_CONSOLE`
+    #[serde(rename = "platformType")]
+    pub platform_type: Option<String>,
+    pub generation: Option<i32>,
+    pub family: Option<i64>,
+    pub page: Option<i32>,
+    pub size: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlatformPageDTO {
+    pub content: Vec<PlatformDTO>,
+    pub pageable: PageableDTO,
+    #[serde(rename = "totalPages")]
+    pub total_pages: i32,
+    #[serde(rename = "totalElements")]
+    pub total_elements: i64,
+    pub last: bool,
+    pub first: bool,
+    pub size: i32,
+    pub number: i32,
+    pub sort: SortDTO,
+    #[serde(rename = "numberOfElements")]
+    pub number_of_elements: i32,
+    pub empty: bool,
 }
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReferenceItemDTO {
+    pub id: i64,
+    pub name: String,
+}
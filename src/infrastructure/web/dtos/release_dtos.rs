@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::release::DatePrecision;
+use crate::infrastructure::web::dtos::common_dtos::{PageableDTO, SortDTO};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReleaseDTO {
+    pub id: i64,
+    #[serde(rename = "gameId")]
+    pub game_id: i64,
+    #[serde(rename = "gameName")]
+    pub game_name: Option<String>,
+    #[serde(rename = "platformId")]
+    pub platform_id: Option<i64>,
+    #[serde(rename = "platformName")]
+    pub platform_name: Option<String>,
+    pub region: Option<String>,
+    pub date: Option<String>,
+    pub precision: DatePrecision,
+    pub human: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReleaseQueryDTO {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    // Comma-separated platform ids, e.g. `48,167`
+    pub platform: Option<String>,
+    pub region: Option<String>,
+    pub page: Option<i32>,
+    pub size: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReleasePageDTO {
+    pub content: Vec<ReleaseDTO>,
+    pub pageable: PageableDTO,
+    #[serde(rename = "totalPages")]
+    pub total_pages: i32,
+    #[serde(rename = "totalElements")]
+    pub total_elements: i64,
+    pub last: bool,
+    pub first: bool,
+    pub size: i32,
+    pub number: i32,
+    pub sort: SortDTO,
+    #[serde(rename = "numberOfElements")]
+    pub number_of_elements: i32,
+    pub empty: bool,
+}
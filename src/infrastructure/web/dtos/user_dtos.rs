@@ -43,6 +43,22 @@ pub struct UserGameDTO {
     pub is_favorite: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BacklogEstimateDTO {
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    #[serde(rename = "gameCount")]
+    pub game_count: usize,
+    #[serde(rename = "hastilyHours")]
+    pub hastily_hours: f64,
+    #[serde(rename = "normallyHours")]
+    pub normally_hours: f64,
+    #[serde(rename = "completelyHours")]
+    pub completely_hours: f64,
+    #[serde(rename = "gamesWithoutEstimate")]
+    pub games_without_estimate: Vec<i64>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UpdateGameStatusRequestDTO {
     pub status: GameStatus,
@@ -4,16 +4,18 @@ use axum::{
     Json,
 };
 use serde_json::json;
+use crate::domain::error::DomainError;
+use crate::infrastructure::web::dtos::common_dtos::FieldErrorDTO;
 
 #[derive(Debug)]
 pub enum AppError {
     InternalServerError(anyhow::Error),
-    #[allow(dead_code)]
     BadRequest(String),
     NotFound(String),
     Unauthorized(String),
-    #[allow(dead_code)]
     Conflict(String),
+    BadGateway(String),
+    ValidationFailed(Vec<FieldErrorDTO>),
 }
 
 impl IntoResponse for AppError {
@@ -27,6 +29,17 @@ impl IntoResponse for AppError {
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
             AppError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
             AppError::Conflict(msg) => (StatusCode::CONFLICT, msg),
+            AppError::BadGateway(msg) => {
+                tracing::error!("Upstream error: {}", msg);
+                (StatusCode::BAD_GATEWAY, "Upstream service error".to_string())
+            }
+            AppError::ValidationFailed(field_errors) => {
+                let body = Json(json!({
+                    "error": "Validation failed",
+                    "fieldErrors": field_errors,
+                }));
+                return (StatusCode::BAD_REQUEST, body).into_response();
+            }
         };
 
         let body = Json(json!({
@@ -37,11 +50,16 @@ impl IntoResponse for AppError {
     }
 }
 
-impl<E> From<E> for AppError
-where
-    E: Into<anyhow::Error>,
-{
-    fn from(err: E) -> Self {
-        Self::InternalServerError(err.into())
+// Single place where domain errors are translated into HTTP responses.
+impl From<DomainError> for AppError {
+    fn from(err: DomainError) -> Self {
+        match err {
+            DomainError::NotFound(msg) => AppError::NotFound(msg),
+            DomainError::Conflict(msg) => AppError::Conflict(msg),
+            DomainError::Validation(msg) => AppError::BadRequest(msg),
+            DomainError::Unauthorized(msg) => AppError::Unauthorized(msg),
+            DomainError::Upstream(msg) => AppError::BadGateway(msg),
+            DomainError::Internal(msg) => AppError::InternalServerError(anyhow::anyhow!(msg)),
+        }
     }
 }
@@ -0,0 +1,53 @@
+use axum::{
+    async_trait,
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use crate::infrastructure::web::dtos::common_dtos::FieldErrorDTO;
+use crate::infrastructure::web::error::AppError;
+
+/// Drop-in replacement for `Json` that reports bodies that don't match the
+/// expected shape as validation errors instead of axum's plain-text 422.
+pub struct JsonBody<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for JsonBody<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Json::<T>::from_request(req, state).await {
+            Ok(Json(value)) => Ok(JsonBody(value)),
+            Err(JsonRejection::JsonDataError(rejection)) => Err(AppError::ValidationFailed(vec![to_field_error(&rejection)])),
+            Err(rejection) => Err(AppError::BadRequest(rejection.body_text())),
+        }
+    }
+}
+
+fn to_field_error(rejection: &(dyn std::error::Error + 'static)) -> FieldErrorDTO {
+    let mut source = rejection.source();
+    while let Some(error) = source {
+        if let Some(error) = error.downcast_ref::<serde_path_to_error::Error<serde_json::Error>>() {
+            let path = error.path().to_string();
+            return FieldErrorDTO {
+                field: if path == "." { "body".to_string() } else { path },
+                message: without_location(error.inner()),
+            };
+        }
+        source = error.source();
+    }
+    FieldErrorDTO { field: "body".to_string(), message: rejection.to_string() }
+}
+
+// serde_json appends "at line X column Y", which means nothing to API clients.
+fn without_location(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    match message.rsplit_once(" at line ") {
+        Some((message, _)) => message.to_string(),
+        None => message,
+    }
+}
@@ -1,10 +1,20 @@
-use crate::domain::game::Game;
+use crate::domain::backlog::BacklogEstimate;
+use crate::domain::game::{Game, GameBatch, TimeToBeat};
+use crate::domain::game_suggestion::GameSuggestion;
+use crate::application::ports::output::image_url_resolver::ImageUrlResolver;
+use crate::domain::image::{Image, ImagePreset, ImageSize};
 use crate::domain::platform::Platform;
 use crate::domain::user::{User, LoginResult, UserGame};
+use crate::domain::catalog::CatalogSyncProgress;
 use crate::domain::page::Page;
-use crate::infrastructure::web::dtos::game_dtos::{GameDTO, GameSummaryDTO, ArtworkDTO, GamePageDTO};
-use crate::infrastructure::web::dtos::platform_dtos::PlatformDTO;
-use crate::infrastructure::web::dtos::user_dtos::{UserDTO, LoginResponseDTO, UserGameDTO, UserGamePageDTO};
+use crate::domain::reference_data::ReferenceItem;
+use crate::domain::release::Release;
+use crate::infrastructure::web::dtos::game_dtos::{GameDTO, GameSummaryDTO, ArtworkDTO, ImageDTO, GamePageDTO, GameBatchResponseDTO, GameSuggestionDTO, AgeRatingDTO, WebsiteDTO, TimeToBeatDTO};
+use crate::infrastructure::web::dtos::platform_dtos::{PlatformDTO, PlatformDetailDTO, PlatformFamilyDTO, PlatformPageDTO, PlatformReleaseDateDTO, PlatformVersionDTO};
+use crate::infrastructure::web::dtos::catalog_dtos::{CatalogSyncProgressDTO, SyncCursorDTO};
+use crate::infrastructure::web::dtos::reference_data_dtos::ReferenceItemDTO;
+use crate::infrastructure::web::dtos::release_dtos::{ReleaseDTO, ReleasePageDTO};
+use crate::infrastructure::web::dtos::user_dtos::{BacklogEstimateDTO, UserDTO, LoginResponseDTO, UserGameDTO, UserGamePageDTO};
 use crate::infrastructure::web::dtos::common_dtos::{PageableDTO, SortDTO};
 
 
@@ -29,51 +39,126 @@ macro_rules! build_page_dto {
             number_of_elements: content_len,
             empty: is_empty,
         }
-    };
+    }};
 }
 
-pub fn to_game_dto(game: &Game) -> GameDTO {
+/// Resolves image URLs for one response. `size` is the size the client asked for; without
+/// one each kind of image keeps its own default.
+pub struct ImageUrls<'a> {
+    pub resolver: &'a dyn ImageUrlResolver,
+    pub size: Option<ImageSize>,
+}
+
+impl ImageUrls<'_> {
+    fn url(&self, image: &Image, default_size: ImageSize) -> String {
+        self.resolver.image_url(image, self.size.unwrap_or(default_size))
+    }
+}
+
+pub fn to_game_dto(game: &Game, images: &ImageUrls) -> GameDTO {
     GameDTO {
         id: game.id,
         name: game.name.clone(),
         release_date: game.release_date.map(|d| d.to_string()),
         rating: game.rating,
-        cover_image_url: game.cover_image_url.clone(),
+        cover_image_url: game.cover.as_ref().map(|c| images.url(c, ImageSize::COVER)),
         platforms: game.platforms.clone(),
+        sources: game.sources.clone(),
+    }
+}
+
+pub fn to_game_suggestion_dto(suggestion: GameSuggestion, images: &ImageUrls) -> GameSuggestionDTO {
+    GameSuggestionDTO {
+        id: suggestion.id,
+        name: suggestion.name,
+        release_year: suggestion.release_year,
+        cover_thumb_url: suggestion.cover.map(|c| images.url(&c, ImageSize::new(ImagePreset::Thumb))),
+    }
+}
+
+pub fn to_game_dto_list(games: Vec<Game>, images: &ImageUrls) -> Vec<GameDTO> {
+    games.iter().map(|g| to_game_dto(g, images)).collect()
+}
+
+pub fn to_game_batch_response_dto(batch: GameBatch, images: &ImageUrls) -> GameBatchResponseDTO {
+    GameBatchResponseDTO {
+        games: to_game_dto_list(batch.games, images),
+        missing_ids: batch.missing_ids,
     }
 }
 
-pub fn to_game_dto_list(games: Vec<Game>) -> Vec<GameDTO> {
-    games.iter().map(to_game_dto).collect()
+pub fn to_image_dto(image: Image, images: &ImageUrls, default_size: ImageSize) -> ImageDTO {
+    let url = images.url(&image, default_size);
+    ImageDTO {
+        id: image.id,
+        alpha_channel: image.alpha_channel,
+        animated: image.animated,
+        checksum: image.checksum,
+        height: image.height,
+        url,
+        image_id: image.image_id,
+        width: image.width,
+    }
 }
 
-pub fn to_game_summary_dto(game: Game) -> GameSummaryDTO {
+pub fn to_game_summary_dto(game: Game, images: &ImageUrls) -> GameSummaryDTO {
     GameSummaryDTO {
-        game: to_game_dto(&game),
+        game: to_game_dto(&game, images),
         summary: game.summary,
         storyline: game.storyline,
         genres: game.genres,
         videos: game.videos,
-        screenshots: game.screenshots,
+        cover: game.cover.map(|c| to_image_dto(c, images, ImageSize::COVER)),
+        screenshots: game.screenshots.into_iter().map(|s| to_image_dto(s, images, ImageSize::SCREENSHOT)).collect(),
         artworks: game.artworks.into_iter().map(|a| ArtworkDTO {
-            id: 0, // TODO: Map fields correctly if available in domain
-            alpha_channel: false,
-            animated: false,
-            artwork_type: 0,
-            checksum: "".to_string(),
+            url: images.url(&a, ImageSize::ARTWORK),
+            id: a.id,
+            alpha_channel: a.alpha_channel,
+            animated: a.animated,
+            artwork_type: a.artwork_type,
+            checksum: a.checksum,
             game: game.id,
-            height: 0,
-            image_id: "".to_string(),
-            url: a.url,
-            width: 0,
+            height: a.height,
+            image_id: a.image_id,
+            width: a.width,
+        }).collect(),
+        developers: game.involved_companies.iter().filter(|c| c.developer).map(|c| c.name.clone()).collect(),
+        publishers: game.involved_companies.iter().filter(|c| c.publisher).map(|c| c.name.clone()).collect(),
+        franchises: game.franchises,
+        collections: game.collections,
+        themes: game.themes,
+        game_modes: game.game_modes,
+        player_perspectives: game.player_perspectives,
+        age_ratings: game.age_ratings.into_iter().map(|r| AgeRatingDTO {
+            organization: r.organization,
+            rating: r.rating,
         }).collect(),
+        websites: game.websites.into_iter().map(|w| WebsiteDTO {
+            url: w.url,
+            category: w.category,
+            trusted: w.trusted,
+        }).collect(),
+        alternative_names: game.alternative_names,
+        time_to_beat: game.time_to_beat.filter(|t| !t.is_empty()).map(to_time_to_beat_dto),
     }
 }
 
-pub fn to_game_page_dto(page: Page<Game>) -> GamePageDTO {
-    let content = to_game_dto_list(page.content);
+pub fn to_time_to_beat_dto(time_to_beat: TimeToBeat) -> TimeToBeatDTO {
+    TimeToBeatDTO {
+        hastily_hours: time_to_beat.hastily.map(hours),
+        normally_hours: time_to_beat.normally.map(hours),
+        completely_hours: time_to_beat.completely.map(hours),
+    }
+}
+
+// Seconds to hours, rounded to one decimal.
+fn hours(seconds: i64) -> f64 {
+    (seconds as f64 / 360.0).round() / 10.0
+}
+
+pub fn to_game_page_dto(page: Page<Game>, images: &ImageUrls) -> GamePageDTO {
     let Page { content: domain_content, page, size, total_elements, total_pages } = page;
-    let dto_content = to_game_dto_list(domain_content);
+    let dto_content = to_game_dto_list(domain_content, images);
     struct PageMeta { page: i32, size: i32, total_elements: i64, total_pages: i32 }
     let meta = PageMeta { page, size, total_elements, total_pages };
 
@@ -81,12 +166,73 @@ pub fn to_game_page_dto(page: Page<Game>) -> GamePageDTO {
 }
 
 
-pub fn to_platform_dto(platform: Platform) -> PlatformDTO {
+pub fn to_platform_dto(platform: &Platform) -> PlatformDTO {
     PlatformDTO {
         id: platform.id,
-        name: platform.name,
+        name: platform.name.clone(),
         generation: platform.generation,
         platform_type: platform.platform_type,
+        abbreviation: platform.abbreviation.clone(),
+        family: platform.family.as_ref().map(|f| PlatformFamilyDTO {
+            id: f.id,
+            name: f.name.clone(),
+        }),
+    }
+}
+
+pub fn to_platform_detail_dto(platform: Platform, images: &ImageUrls) -> PlatformDetailDTO {
+    PlatformDetailDTO {
+        platform: to_platform_dto(&platform),
+        logo: platform.logo.map(|l| to_image_dto(l, images, ImageSize::LOGO)),
+        versions: platform.versions.into_iter().map(|v| PlatformVersionDTO {
+            id: v.id,
+            name: v.name,
+            release_dates: v.release_dates.into_iter().map(|d| PlatformReleaseDateDTO {
+                date: d.date.map(|date| date.to_string()),
+                region: d.region,
+            }).collect(),
+        }).collect(),
+    }
+}
+
+pub fn to_platform_page_dto(page: Page<Platform>) -> PlatformPageDTO {
+    let Page { content: domain_content, page, size, total_elements, total_pages } = page;
+    let dto_content: Vec<PlatformDTO> = domain_content.iter().map(to_platform_dto).collect();
+
+    struct PageMeta { page: i32, size: i32, total_elements: i64, total_pages: i32 }
+    let meta = PageMeta { page, size, total_elements, total_pages };
+
+    build_page_dto!(meta, dto_content, PlatformPageDTO)
+}
+
+pub fn to_release_dto(release: Release) -> ReleaseDTO {
+    ReleaseDTO {
+        id: release.id,
+        game_id: release.game_id,
+        game_name: release.game_name,
+        platform_id: release.platform_id,
+        platform_name: release.platform_name,
+        region: release.region,
+        date: release.date.map(|d| d.to_string()),
+        precision: release.precision,
+        human: release.human,
+    }
+}
+
+pub fn to_release_page_dto(page: Page<Release>) -> ReleasePageDTO {
+    let Page { content: domain_content, page, size, total_elements, total_pages } = page;
+    let dto_content: Vec<ReleaseDTO> = domain_content.into_iter().map(to_release_dto).collect();
+
+    struct PageMeta { page: i32, size: i32, total_elements: i64, total_pages: i32 }
+    let meta = PageMeta { page, size, total_elements, total_pages };
+
+    build_page_dto!(meta, dto_content, ReleasePageDTO)
+}
+
+pub fn to_reference_item_dto(item: ReferenceItem) -> ReferenceItemDTO {
+    ReferenceItemDTO {
+        id: item.id,
+        name: item.name,
     }
 }
 
@@ -116,6 +262,17 @@ pub fn to_user_game_dto(user_game: UserGame) -> UserGameDTO {
     }
 }
 
+pub fn to_backlog_estimate_dto(user_id: String, estimate: BacklogEstimate) -> BacklogEstimateDTO {
+    BacklogEstimateDTO {
+        user_id,
+        game_count: estimate.game_count,
+        hastily_hours: hours(estimate.hastily),
+        normally_hours: hours(estimate.normally),
+        completely_hours: hours(estimate.completely),
+        games_without_estimate: estimate.games_without_estimate,
+    }
+}
+
 pub fn to_user_game_dto_list(user_games: Vec<UserGame>) -> Vec<UserGameDTO> {
     user_games.into_iter().map(to_user_game_dto).collect()
 }
@@ -129,3 +286,25 @@ pub fn to_user_game_page_dto(page: Page<UserGame>) -> UserGamePageDTO {
 
     build_page_dto!(meta, dto_content, UserGamePageDTO)
 }
+
+pub fn to_catalog_sync_progress_dto(progress: CatalogSyncProgress) -> CatalogSyncProgressDTO {
+    let percent_complete = progress.remaining_games.map(|remaining| {
+        let expected = progress.synced_games + remaining;
+        if expected > 0 { progress.synced_games as f64 * 100.0 / expected as f64 } else { 100.0 }
+    });
+
+    CatalogSyncProgressDTO {
+        status: progress.status,
+        cursor: SyncCursorDTO {
+            updated_at: progress.cursor.updated_at,
+            game_id: progress.cursor.game_id,
+        },
+        synced_games: progress.synced_games,
+        remaining_games: progress.remaining_games,
+        percent_complete,
+        total_games: progress.total_games,
+        started_at: progress.started_at.map(|t| t.to_rfc3339()),
+        finished_at: progress.finished_at.map(|t| t.to_rfc3339()),
+        last_error: progress.last_error,
+    }
+}
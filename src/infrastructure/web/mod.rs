@@ -3,3 +3,5 @@ pub mod routes;
 pub mod error;
 pub mod mappers;
 pub mod auth_middleware;
+pub mod validation;
+pub mod json_body;
@@ -0,0 +1,30 @@
+use axum::{
+    extract::State,
+    routing::get,
+    Json, Router,
+};
+use std::sync::Arc;
+use crate::application::ports::input::catalog_sync_service::CatalogSyncService;
+use crate::infrastructure::web::dtos::catalog_dtos::CatalogSyncProgressDTO;
+use crate::infrastructure::web::error::AppError;
+use crate::infrastructure::web::mappers;
+
+#[derive(Clone)]
+pub struct CatalogAppState {
+    pub catalog_sync_service: Arc<dyn CatalogSyncService>,
+}
+
+pub fn routes(catalog_sync_service: Arc<dyn CatalogSyncService>) -> Router {
+    let state = CatalogAppState { catalog_sync_service };
+    Router::new()
+        .route("/catalog/sync", get(get_sync_progress))
+        .with_state(state)
+}
+
+async fn get_sync_progress(
+    State(state): State<CatalogAppState>,
+) -> Result<Json<CatalogSyncProgressDTO>, AppError> {
+    let progress = state.catalog_sync_service.progress().await?;
+
+    Ok(Json(mappers::to_catalog_sync_progress_dto(progress)))
+}
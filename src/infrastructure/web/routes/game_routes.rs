@@ -1,83 +1,141 @@
 use axum::{
     extract::{Path, Query, State},
+    http::{HeaderMap, HeaderValue},
     routing::{get, post},
     Json, Router,
 };
 use std::sync::Arc;
+use uuid::Uuid;
 use crate::application::ports::input::game_service::GameService;
-use crate::infrastructure::web::dtos::game_dtos::{GameDTO, GameSummaryDTO, GameFilterRequestDTO, GamePageDTO};
+use crate::application::ports::output::image_url_resolver::ImageUrlResolver;
+use crate::domain::game::Game;
+use crate::infrastructure::web::dtos::game_dtos::{AutocompleteQueryDTO, GameDTO, GameSuggestionDTO, GameSummaryDTO, GameFilterRequestDTO, GamePageDTO, GameBatchResponseDTO};
+use crate::infrastructure::web::dtos::common_dtos::ImageSizeQueryDTO;
 use crate::infrastructure::web::error::AppError;
+use crate::infrastructure::web::json_body::JsonBody;
 use crate::infrastructure::web::mappers;
+use crate::infrastructure::web::validation;
+use crate::infrastructure::web::auth_middleware::MaybeAuthUser;
 
 // AppState to hold the service
 #[derive(Clone)]
 pub struct GameAppState {
     pub game_service: Arc<dyn GameService>,
+    pub max_batch_size: usize,
+    pub image_urls: Arc<dyn ImageUrlResolver>,
 }
 
-pub fn routes(game_service: Arc<dyn GameService>) -> Router {
-    let state = GameAppState { game_service };
+pub fn routes(game_service: Arc<dyn GameService>, max_batch_size: usize, image_urls: Arc<dyn ImageUrlResolver>) -> Router {
+    let state = GameAppState { game_service, max_batch_size, image_urls };
     Router::new()
         .route("/games/search", get(search_games_by_name))
+        .route("/games/autocomplete", get(autocomplete_games))
         .route("/games/:id", get(get_game_by_id))
+        .route("/games/:id/similar", get(get_similar_games))
         .route("/games/batch", post(get_games_by_ids))
         .route("/games/filter", post(filter_games))
         .with_state(state)
 }
 
+// Set when some of the games are the last known data, served because IGDB is unavailable.
+const STALE_HEADER: &str = "x-stale-data";
+
+fn stale_headers<'a>(games: impl IntoIterator<Item = &'a Game>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if games.into_iter().any(|game| game.stale) {
+        headers.insert(STALE_HEADER, HeaderValue::from_static("true"));
+    }
+    headers
+}
+
 #[derive(serde::Deserialize)]
 struct SearchQuery {
     name: String,
+    #[serde(rename = "imageSize")]
+    image_size: Option<String>,
 }
 
 async fn search_games_by_name(
     State(state): State<GameAppState>,
     Query(query): Query<SearchQuery>,
 ) -> Result<Json<Vec<GameDTO>>, AppError> {
-    let games = state.game_service.search_games_by_name(&query.name).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    let images = mappers::ImageUrls { resolver: state.image_urls.as_ref(), size: validation::to_image_size(query.image_size.as_deref())? };
+    let games = state.game_service.search_games_by_name(&query.name).await?;
 
-    let game_dtos = games.into_iter().map(|g| mappers::to_game_dto(&g)).collect();
+    let game_dtos = games.into_iter().map(|g| mappers::to_game_dto(&g, &images)).collect();
     Ok(Json(game_dtos))
 }
 
+async fn autocomplete_games(
+    State(state): State<GameAppState>,
+    Query(query): Query<AutocompleteQueryDTO>,
+) -> Result<Json<Vec<GameSuggestionDTO>>, AppError> {
+    let images = mappers::ImageUrls { resolver: state.image_urls.as_ref(), size: validation::to_image_size(query.image_size.as_deref())? };
+    let (text, limit) = validation::to_autocomplete_query(&query)?;
+
+    let suggestions = state.game_service.autocomplete(&text, limit).await?;
+
+    Ok(Json(suggestions.into_iter().map(|s| mappers::to_game_suggestion_dto(s, &images)).collect()))
+}
+
 async fn get_game_by_id(
     State(state): State<GameAppState>,
     Path(id): Path<i64>,
-) -> Result<Json<GameSummaryDTO>, AppError> {
-    let game_opt = state.game_service.get_game_by_id(id).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    Query(query): Query<ImageSizeQueryDTO>,
+) -> Result<(HeaderMap, Json<GameSummaryDTO>), AppError> {
+    let images = mappers::ImageUrls { resolver: state.image_urls.as_ref(), size: validation::to_image_size(query.image_size.as_deref())? };
+    let game_opt = state.game_service.get_game_by_id(id).await?;
 
     match game_opt {
-        Some(game) => Ok(Json(mappers::to_game_summary_dto(game))),
+        Some(game) => Ok((stale_headers([&game]), Json(mappers::to_game_summary_dto(game, &images)))),
         None => Err(AppError::NotFound(format!("Game with id {} not found", id))),
     }
 }
 
 async fn get_games_by_ids(
     State(state): State<GameAppState>,
-    Json(ids): Json<Vec<i64>>,
-) -> Result<Json<Vec<GameDTO>>, AppError> {
-    let games = state.game_service.get_games_by_ids(&ids).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    Query(query): Query<ImageSizeQueryDTO>,
+    JsonBody(ids): JsonBody<Vec<i64>>,
+) -> Result<(HeaderMap, Json<GameBatchResponseDTO>), AppError> {
+    let images = mappers::ImageUrls { resolver: state.image_urls.as_ref(), size: validation::to_image_size(query.image_size.as_deref())? };
+    if ids.len() > state.max_batch_size {
+        return Err(AppError::BadRequest(format!("A batch may contain at most {} ids, got {}", state.max_batch_size, ids.len())));
+    }
 
-    let game_dtos = games.into_iter().map(|g| mappers::to_game_dto(&g)).collect();
-    Ok(Json(game_dtos))
+    let batch = state.game_service.get_games_by_ids(&ids).await?;
+
+    Ok((stale_headers(&batch.games), Json(mappers::to_game_batch_response_dto(batch, &images))))
 }
 
 async fn filter_games(
     State(state): State<GameAppState>,
-    Json(request): Json<GameFilterRequestDTO>,
+    Query(query): Query<ImageSizeQueryDTO>,
+    JsonBody(request): JsonBody<GameFilterRequestDTO>,
 ) -> Result<Json<GamePageDTO>, AppError> {
+    let images = mappers::ImageUrls { resolver: state.image_urls.as_ref(), size: validation::to_image_size(query.image_size.as_deref())? };
+    let filter = validation::to_game_filter(&request)?;
     let limit = request.limit.unwrap_or(10);
     let offset = request.offset.unwrap_or(0);
 
-    let page = state.game_service.filter_games(
-        &request.filter,
-        request.sort.as_deref().unwrap_or(""),
-        limit,
-        offset
-    ).await.map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    let page = state.game_service.filter_games(&filter, limit, offset).await?;
+
+    Ok(Json(mappers::to_game_page_dto(page, &images)))
+}
+
+async fn get_similar_games(
+    State(state): State<GameAppState>,
+    auth_user: MaybeAuthUser,
+    Path(id): Path<i64>,
+    Query(query): Query<ImageSizeQueryDTO>,
+) -> Result<(HeaderMap, Json<Vec<GameDTO>>), AppError> {
+    let images = mappers::ImageUrls { resolver: state.image_urls.as_ref(), size: validation::to_image_size(query.image_size.as_deref())? };
+    let user_id = match auth_user.0 {
+        Some(claims) => Some(Uuid::parse_str(&claims.user_id).map_err(|_| AppError::Unauthorized("Invalid user in token".to_string()))?),
+        None => None,
+    };
 
-    Ok(Json(mappers::to_game_page_dto(page)))
+    match state.game_service.get_similar_games(id, user_id).await? {
+        Some(games) => Ok((stale_headers(&games), Json(mappers::to_game_dto_list(games, &images)))),
+        None => Err(AppError::NotFound(format!("Game with id {} not found", id))),
+    }
 }
@@ -1,15 +1,47 @@
 use axum::{
+    extract::State,
     routing::get,
     Json, Router,
 };
 use serde_json::{json, Value};
+use std::sync::Arc;
+use crate::infrastructure::cache::cached_platform_provider::CachedPlatformProvider;
+use crate::infrastructure::igdb::circuit_breaker::CircuitBreaker;
+use crate::infrastructure::igdb::request_coalescer::RequestCoalescer;
+use crate::infrastructure::igdb::token_provider::TwitchTokenProvider;
+use crate::infrastructure::persistence::cached_game_provider::SqliteCachedGameProvider;
 
-pub fn routes() -> Router {
-    Router::new().route("/health", get(health_check))
+#[derive(Clone)]
+pub struct HealthAppState {
+    pub game_cache: Arc<SqliteCachedGameProvider>,
+    pub platform_cache: Arc<CachedPlatformProvider>,
+    pub igdb_circuit_breaker: Arc<CircuitBreaker>,
+    pub twitch_token: Arc<TwitchTokenProvider>,
+    pub igdb_requests: Arc<RequestCoalescer>,
 }
 
-async fn health_check() -> Json<Value> {
+pub fn routes(
+    game_cache: Arc<SqliteCachedGameProvider>,
+    platform_cache: Arc<CachedPlatformProvider>,
+    igdb_circuit_breaker: Arc<CircuitBreaker>,
+    twitch_token: Arc<TwitchTokenProvider>,
+    igdb_requests: Arc<RequestCoalescer>,
+) -> Router {
+    let state = HealthAppState { game_cache, platform_cache, igdb_circuit_breaker, twitch_token, igdb_requests };
+    Router::new()
+        .route("/health", get(health_check))
+        .with_state(state)
+}
+
+async fn health_check(
+    State(state): State<HealthAppState>,
+) -> Json<Value> {
     Json(json!({
-        "status": "UP"
+        "status": "UP",
+        "gameCache": state.game_cache.stats(),
+        "platformCache": state.platform_cache.status(),
+        "igdbCircuitBreaker": state.igdb_circuit_breaker.status(),
+        "twitchToken": state.twitch_token.status(),
+        "igdbRequests": state.igdb_requests.stats(),
     }))
 }
@@ -8,8 +8,9 @@ use axum::{
 use std::sync::Arc;
 use uuid::Uuid;
 use crate::application::ports::input::library_service::LibraryService;
-use crate::infrastructure::web::dtos::user_dtos::{UpdateGameStatusRequestDTO, UserGameDTO, UserGamePageDTO};
+use crate::infrastructure::web::dtos::user_dtos::{BacklogEstimateDTO, UpdateGameStatusRequestDTO, UserGameDTO, UserGamePageDTO};
 use crate::infrastructure::web::error::AppError;
+use crate::infrastructure::web::json_body::JsonBody;
 use crate::infrastructure::web::mappers;
 use crate::infrastructure::web::auth_middleware::AuthUser;
 
@@ -25,6 +26,7 @@ pub fn routes(library_service: Arc<dyn LibraryService>) -> Router {
         .route("/users/:user_id/games/:game_id", get(get_user_game_status).put(upsert_game_in_library).delete(remove_game_from_library))
         .route("/users/:user_id/games/:game_id/favorite", post(add_game_to_favorites).delete(remove_game_from_favorites))
         .route("/users/:user_id/favorites", get(list_favorite_games))
+        .route("/users/:user_id/backlog/estimate", get(estimate_backlog))
         .with_state(state)
 }
 
@@ -43,12 +45,23 @@ async fn list_user_library(
 ) -> Result<Json<Vec<UserGameDTO>>, AppError> {
     check_authorization(&auth_user, user_id)?;
 
-    let user_games = state.library_service.list_user_library(user_id).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    let user_games = state.library_service.list_user_library(user_id).await?;
 
     Ok(Json(mappers::to_user_game_dto_list(user_games)))
 }
 
+async fn estimate_backlog(
+    State(state): State<LibraryAppState>,
+    auth_user: AuthUser,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<BacklogEstimateDTO>, AppError> {
+    check_authorization(&auth_user, user_id)?;
+
+    let estimate = state.library_service.estimate_backlog(user_id).await?;
+
+    Ok(Json(mappers::to_backlog_estimate_dto(user_id.to_string(), estimate)))
+}
+
 async fn get_user_game_status(
     State(state): State<LibraryAppState>,
     auth_user: AuthUser,
@@ -56,8 +69,7 @@ async fn get_user_game_status(
 ) -> Result<Json<UserGameDTO>, AppError> {
     check_authorization(&auth_user, user_id)?;
 
-    let user_game_opt = state.library_service.get_user_game_status(user_id, game_id).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    let user_game_opt = state.library_service.get_user_game_status(user_id, game_id).await?;
 
     match user_game_opt {
         Some(user_game) => Ok(Json(mappers::to_user_game_dto(user_game))),
@@ -69,12 +81,11 @@ async fn upsert_game_in_library(
     State(state): State<LibraryAppState>,
     auth_user: AuthUser,
     Path((user_id, game_id)): Path<(Uuid, i64)>,
-    Json(request): Json<UpdateGameStatusRequestDTO>,
+    JsonBody(request): JsonBody<UpdateGameStatusRequestDTO>,
 ) -> Result<Response, AppError> {
     check_authorization(&auth_user, user_id)?;
 
-    let result = state.library_service.upsert_game_in_library(user_id, game_id, request.status).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    let result = state.library_service.upsert_game_in_library(user_id, game_id, request.status).await?;
 
     match result {
         Some(user_game) => Ok(Json(mappers::to_user_game_dto(user_game)).into_response()),
@@ -89,8 +100,7 @@ async fn remove_game_from_library(
 ) -> Result<StatusCode, AppError> {
     check_authorization(&auth_user, user_id)?;
 
-    state.library_service.remove_game_from_library(user_id, game_id).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    state.library_service.remove_game_from_library(user_id, game_id).await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -102,8 +112,7 @@ async fn add_game_to_favorites(
 ) -> Result<Json<UserGameDTO>, AppError> {
     check_authorization(&auth_user, user_id)?;
 
-    let user_game = state.library_service.add_game_to_favorites(user_id, game_id).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    let user_game = state.library_service.add_game_to_favorites(user_id, game_id).await?;
 
     Ok(Json(mappers::to_user_game_dto(user_game)))
 }
@@ -115,8 +124,7 @@ async fn remove_game_from_favorites(
 ) -> Result<StatusCode, AppError> {
     check_authorization(&auth_user, user_id)?;
 
-    state.library_service.remove_game_from_favorites(user_id, game_id).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    state.library_service.remove_game_from_favorites(user_id, game_id).await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -138,8 +146,7 @@ async fn list_favorite_games(
     let page = params.page.unwrap_or(0);
     let size = params.size.unwrap_or(20);
 
-    let page_result = state.library_service.list_favorite_games(user_id, page, size).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    let page_result = state.library_service.list_favorite_games(user_id, page, size).await?;
 
     Ok(Json(mappers::to_user_game_page_dto(page_result)))
 }
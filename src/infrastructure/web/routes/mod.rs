@@ -3,3 +3,6 @@ pub mod platform_routes;
 pub mod user_routes;
 pub mod library_routes;
 pub mod health_routes;
+pub mod reference_data_routes;
+pub mod catalog_routes;
+pub mod release_routes;
@@ -1,32 +1,53 @@
 use axum::{
     routing::get,
     Json, Router,
-    extract::State,
+    extract::{Path, Query, State},
 };
 use std::sync::Arc;
 use crate::application::ports::input::platform_service::PlatformService;
-use crate::infrastructure::web::dtos::platform_dtos::PlatformDTO;
+use crate::application::ports::output::image_url_resolver::ImageUrlResolver;
+use crate::infrastructure::web::dtos::platform_dtos::{PlatformDetailDTO, PlatformFilterQueryDTO, PlatformPageDTO};
+use crate::infrastructure::web::dtos::common_dtos::ImageSizeQueryDTO;
 use crate::infrastructure::web::error::AppError;
 use crate::infrastructure::web::mappers;
+use crate::infrastructure::web::validation;
 
 #[derive(Clone)]
 pub struct PlatformAppState {
     pub platform_service: Arc<dyn PlatformService>,
+    pub image_urls: Arc<dyn ImageUrlResolver>,
 }
 
-pub fn routes(platform_service: Arc<dyn PlatformService>) -> Router {
-    let state = PlatformAppState { platform_service };
+pub fn routes(platform_service: Arc<dyn PlatformService>, image_urls: Arc<dyn ImageUrlResolver>) -> Router {
+    let state = PlatformAppState { platform_service, image_urls };
     Router::new()
         .route("/platforms", get(list_platforms))
+        .route("/platforms/:id", get(get_platform_by_id))
         .with_state(state)
 }
 
 async fn list_platforms(
     State(state): State<PlatformAppState>,
-) -> Result<Json<Vec<PlatformDTO>>, AppError> {
-    let platforms = state.platform_service.list_platforms().await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    Query(query): Query<PlatformFilterQueryDTO>,
+) -> Result<Json<PlatformPageDTO>, AppError> {
+    let filter = validation::to_platform_filter(&query)?;
+    let page = query.page.unwrap_or(0);
+    let size = query.size.unwrap_or(50);
 
-    let platform_dtos = platforms.into_iter().map(mappers::to_platform_dto).collect();
-    Ok(Json(platform_dtos))
+    let platforms = state.platform_service.list_platforms(&filter, page, size).await?;
+
+    Ok(Json(mappers::to_platform_page_dto(platforms)))
+}
+
+async fn get_platform_by_id(
+    State(state): State<PlatformAppState>,
+    Path(id): Path<i64>,
+    Query(query): Query<ImageSizeQueryDTO>,
+) -> Result<Json<PlatformDetailDTO>, AppError> {
+    let images = mappers::ImageUrls { resolver: state.image_urls.as_ref(), size: validation::to_image_size(query.image_size.as_deref())? };
+
+    match state.platform_service.get_platform_by_id(id).await? {
+        Some(platform) => Ok(Json(mappers::to_platform_detail_dto(platform, &images))),
+        None => Err(AppError::NotFound(format!("Platform with id {} not found", id))),
+    }
 }
@@ -0,0 +1,48 @@
+use axum::{
+    extract::State,
+    http::header,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use crate::application::ports::input::reference_data_service::ReferenceDataService;
+use crate::domain::reference_data::ReferenceDataKind;
+use crate::infrastructure::web::error::AppError;
+use crate::infrastructure::web::mappers;
+
+#[derive(Clone)]
+pub struct ReferenceDataAppState {
+    pub reference_data_service: Arc<dyn ReferenceDataService>,
+    pub max_age: Duration,
+}
+
+pub fn routes(reference_data_service: Arc<dyn ReferenceDataService>, max_age: Duration) -> Router {
+    let state = ReferenceDataAppState { reference_data_service, max_age };
+    Router::new()
+        .route("/genres", get(list_genres))
+        .route("/themes", get(list_themes))
+        .route("/game-modes", get(list_game_modes))
+        .with_state(state)
+}
+
+async fn list_genres(State(state): State<ReferenceDataAppState>) -> Result<impl IntoResponse, AppError> {
+    list(&state, ReferenceDataKind::Genre).await
+}
+
+async fn list_themes(State(state): State<ReferenceDataAppState>) -> Result<impl IntoResponse, AppError> {
+    list(&state, ReferenceDataKind::Theme).await
+}
+
+async fn list_game_modes(State(state): State<ReferenceDataAppState>) -> Result<impl IntoResponse, AppError> {
+    list(&state, ReferenceDataKind::GameMode).await
+}
+
+async fn list(state: &ReferenceDataAppState, kind: ReferenceDataKind) -> Result<impl IntoResponse, AppError> {
+    let items = state.reference_data_service.list(kind).await?;
+
+    let cache_control = format!("public, max-age={}", state.max_age.as_secs());
+    let item_dtos: Vec<_> = items.into_iter().map(mappers::to_reference_item_dto).collect();
+    Ok(([(header::CACHE_CONTROL, cache_control)], Json(item_dtos)))
+}
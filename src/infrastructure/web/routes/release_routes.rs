@@ -0,0 +1,36 @@
+use axum::{
+    routing::get,
+    Json, Router,
+    extract::{Query, State},
+};
+use std::sync::Arc;
+use crate::application::ports::input::release_service::ReleaseService;
+use crate::infrastructure::web::dtos::release_dtos::{ReleasePageDTO, ReleaseQueryDTO};
+use crate::infrastructure::web::error::AppError;
+use crate::infrastructure::web::mappers;
+use crate::infrastructure::web::validation;
+
+#[derive(Clone)]
+pub struct ReleaseAppState {
+    pub release_service: Arc<dyn ReleaseService>,
+}
+
+pub fn routes(release_service: Arc<dyn ReleaseService>) -> Router {
+    let state = ReleaseAppState { release_service };
+    Router::new()
+        .route("/releases", get(list_releases))
+        .with_state(state)
+}
+
+async fn list_releases(
+    State(state): State<ReleaseAppState>,
+    Query(query): Query<ReleaseQueryDTO>,
+) -> Result<Json<ReleasePageDTO>, AppError> {
+    let filter = validation::to_release_filter(&query, chrono::Utc::now().date_naive())?;
+    let page = query.page.unwrap_or(0);
+    let size = query.size.unwrap_or(50);
+
+    let releases = state.release_service.list_releases(&filter, page, size).await?;
+
+    Ok(Json(mappers::to_release_page_dto(releases)))
+}
@@ -7,6 +7,7 @@ use std::sync::Arc;
 use crate::application::ports::input::user_service::UserService;
 use crate::infrastructure::web::dtos::user_dtos::{UserDTO, UserRegistrationRequestDTO, LoginRequestDTO, LoginResponseDTO};
 use crate::infrastructure::web::error::AppError;
+use crate::infrastructure::web::json_body::JsonBody;
 use crate::infrastructure::web::mappers;
 
 #[derive(Clone)]
@@ -24,23 +25,18 @@ pub fn routes(user_service: Arc<dyn UserService>) -> Router {
 
 async fn register_user(
     State(state): State<UserAppState>,
-    Json(request): Json<UserRegistrationRequestDTO>,
+    JsonBody(request): JsonBody<UserRegistrationRequestDTO>,
 ) -> Result<Json<UserDTO>, AppError> {
-    let user = state.user_service.register_user(&request.username, &request.email, &request.password).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    let user = state.user_service.register_user(&request.username, &request.email, &request.password).await?;
 
     Ok(Json(mappers::to_user_dto(user)))
 }
 
 async fn login_user(
     State(state): State<UserAppState>,
-    Json(request): Json<LoginRequestDTO>,
+    JsonBody(request): JsonBody<LoginRequestDTO>,
 ) -> Result<Json<LoginResponseDTO>, AppError> {
-    let login_result_opt = state.user_service.login_user(&request.email, &request.password).await
-        .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+    let login_result = state.user_service.login_user(&request.email, &request.password).await?;
 
-    match login_result_opt {
-        Some(login_result) => Ok(Json(mappers::to_login_response_dto(login_result))),
-        None => Err(AppError::Unauthorized("Invalid credentials".to_string())),
-    }
+    Ok(Json(mappers::to_login_response_dto(login_result)))
 }
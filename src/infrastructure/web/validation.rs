@@ -0,0 +1,290 @@
+use chrono::{Duration, NaiveDate};
+use crate::domain::game_filter::{GameFilter, GameSort, GameSortField, SortDirection};
+use crate::domain::platform::PlatformType;
+use crate::domain::platform_filter::PlatformFilter;
+use crate::domain::release::{ReleaseFilter, ReleaseRegion};
+use crate::domain::image::ImageSize;
+use crate::infrastructure::web::dtos::common_dtos::FieldErrorDTO;
+use crate::infrastructure::web::dtos::game_dtos::{AutocompleteQueryDTO, GameFilterRequestDTO};
+use crate::infrastructure::web::dtos::platform_dtos::PlatformFilterQueryDTO;
+use crate::infrastructure::web::dtos::release_dtos::ReleaseQueryDTO;
+use crate::infrastructure::web::error::AppError;
+
+const MAX_FILTER_VALUES: usize = 50;
+const MAX_NAME_LENGTH: usize = 100;
+const MIN_RELEASE_YEAR: i32 = 1950;
+const MAX_RELEASE_YEAR: i32 = 2100;
+const MAX_PAGE_SIZE: i32 = 50;
+const DEFAULT_AUTOCOMPLETE_LIMIT: usize = 10;
+const MAX_AUTOCOMPLETE_LIMIT: usize = 20;
+// IGDB returns at most 500 records per query.
+const MAX_PLATFORM_PAGE_SIZE: i32 = 500;
+const MAX_RELEASE_PAGE_SIZE: i32 = 500;
+const DEFAULT_RELEASE_WINDOW_DAYS: i64 = 90;
+const MAX_RELEASE_WINDOW_DAYS: i64 = 366;
+
+/// Collects field-level errors so the client receives all of them at once.
+#[derive(Default)]
+struct FieldErrors(Vec<FieldErrorDTO>);
+
+impl FieldErrors {
+    fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.0.push(FieldErrorDTO {
+            field: field.to_string(),
+            message: message.into(),
+        });
+    }
+
+    fn into_result<T>(self, value: T) -> Result<T, AppError> {
+        if self.0.is_empty() {
+            Ok(value)
+        } else {
+            Err(AppError::ValidationFailed(self.0))
+        }
+    }
+}
+
+/// Validates a filter request and converts it into the provider-neutral `GameFilter`.
+pub fn to_game_filter(request: &GameFilterRequestDTO) -> Result<GameFilter, AppError> {
+    let mut errors = FieldErrors::default();
+
+    if request.platform_ids.len() > MAX_FILTER_VALUES {
+        errors.add("platformIds", format!("At most {} platform ids are allowed", MAX_FILTER_VALUES));
+    }
+    if request.platform_ids.iter().any(|id| *id <= 0) {
+        errors.add("platformIds", "Platform ids must be positive");
+    }
+
+    if request.genres.len() > MAX_FILTER_VALUES {
+        errors.add("genres", format!("At most {} genres are allowed", MAX_FILTER_VALUES));
+    }
+    if request.genres.iter().any(|g| g.trim().is_empty() || g.len() > MAX_NAME_LENGTH) {
+        errors.add("genres", format!("Genre names must be between 1 and {} characters", MAX_NAME_LENGTH));
+    }
+
+    for (field, year) in [("releaseYearFrom", request.release_year_from), ("releaseYearTo", request.release_year_to)] {
+        if let Some(year) = year {
+            if !(MIN_RELEASE_YEAR..=MAX_RELEASE_YEAR).contains(&year) {
+                errors.add(field, format!("Year must be between {} and {}", MIN_RELEASE_YEAR, MAX_RELEASE_YEAR));
+            }
+        }
+    }
+    if let (Some(from), Some(to)) = (request.release_year_from, request.release_year_to) {
+        if from > to {
+            errors.add("releaseYearFrom", "releaseYearFrom must not be greater than releaseYearTo");
+        }
+    }
+
+    for (field, rating) in [("ratingMin", request.rating_min), ("ratingMax", request.rating_max)] {
+        if let Some(rating) = rating {
+            if !(0.0..=10.0).contains(&rating) {
+                errors.add(field, "Rating must be between 0 and 10");
+            }
+        }
+    }
+    if let (Some(min), Some(max)) = (request.rating_min, request.rating_max) {
+        if min > max {
+            errors.add("ratingMin", "ratingMin must not be greater than ratingMax");
+        }
+    }
+
+    let name_contains = request.name_contains.as_deref().map(str::trim);
+    if let Some(name) = name_contains {
+        if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+            errors.add("nameContains", format!("nameContains must be between 1 and {} characters", MAX_NAME_LENGTH));
+        }
+    }
+
+    let sort = request.sort.as_ref().and_then(|sort| {
+        let field = match sort.field.as_str() {
+            "name" => Some(GameSortField::Name),
+            "releaseDate" => Some(GameSortField::ReleaseDate),
+            "rating" => Some(GameSortField::Rating),
+            _ => {
+                errors.add("sort.field", "Sort field must be one of: name, releaseDate, rating");
+                None
+            }
+        };
+        let direction = match sort.direction.as_deref().unwrap_or("asc") {
+            "asc" => Some(SortDirection::Asc),
+            "desc" => Some(SortDirection::Desc),
+            _ => {
+                errors.add("sort.direction", "Sort direction must be one of: asc, desc");
+                None
+            }
+        };
+        Some(GameSort { field: field?, direction: direction? })
+    });
+
+    if let Some(limit) = request.limit {
+        if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+            errors.add("limit", format!("limit must be between 1 and {}", MAX_PAGE_SIZE));
+        }
+    }
+    if request.offset.is_some_and(|offset| offset < 0) {
+        errors.add("offset", "offset must not be negative");
+    }
+
+    errors.into_result(GameFilter {
+        platform_ids: request.platform_ids.clone(),
+        genres: request.genres.iter().map(|g| g.trim().to_string()).collect(),
+        release_year_from: request.release_year_from,
+        release_year_to: request.release_year_to,
+        rating_min: request.rating_min,
+        rating_max: request.rating_max,
+        name_contains: name_contains.map(str::to_string),
+        sort,
+    })
+}
+
+/// Parses the optional `imageSize` query parameter of the game endpoints.
+pub fn to_image_size(image_size: Option<&str>) -> Result<Option<ImageSize>, AppError> {
+    let mut errors = FieldErrors::default();
+
+    let size = image_size.and_then(|value| match value.parse() {
+        Ok(size) => Some(size),
+        Err(message) => {
+            errors.add("imageSize", message);
+            None
+        }
+    });
+
+    errors.into_result(size)
+}
+
+/// Validates the `GET /games/autocomplete` query, returning the trimmed text and the limit.
+pub fn to_autocomplete_query(query: &AutocompleteQueryDTO) -> Result<(String, usize), AppError> {
+    let mut errors = FieldErrors::default();
+
+    let text = query.q.as_deref().unwrap_or_default().trim().to_string();
+    if text.is_empty() {
+        errors.add("q", "q must not be blank");
+    } else if text.chars().count() > MAX_NAME_LENGTH {
+        errors.add("q", format!("q must be at most {} characters", MAX_NAME_LENGTH));
+    }
+
+    let limit = query.limit.unwrap_or(DEFAULT_AUTOCOMPLETE_LIMIT);
+    if !(1..=MAX_AUTOCOMPLETE_LIMIT).contains(&limit) {
+        errors.add("limit", format!("limit must be between 1 and {}", MAX_AUTOCOMPLETE_LIMIT));
+    }
+
+    errors.into_result((text, limit))
+}
+
+/// Validates the `GET /platforms` query and converts it into a `PlatformFilter`.
+pub fn to_platform_filter(query: &PlatformFilterQueryDTO) -> Result<PlatformFilter, AppError> {
+    let mut errors = FieldErrors::default();
+
+    let mut platform_types = Vec::new();
+    for value in query.platform_type.iter().flat_map(|types| types.split(',')) {
+        let platform_type = match value.trim() {
+            "CONSOLE" => Some(PlatformType::Console),
+            "ARCADE" => Some(PlatformType::Arcade),
+            "PLATFORM" => Some(PlatformType::Platform),
+            "OPERATING_SYSTEM" => Some(PlatformType::OperatingSystem),
+            "PORTABLE_CONSOLE" => Some(PlatformType::PortableConsole),
+            "COMPUTER" => Some(PlatformType::Computer),
+            "UNKNOWN" => Some(PlatformType::Unknown),
+            _ => None,
+        };
+        match platform_type {
+            Some(platform_type) => platform_types.push(platform_type),
+            None => errors.add(
+                "platformType",
+                format!("Unknown platform type '{}'; expected one of: CONSOLE, ARCADE, PLATFORM, OPERATING_SYSTEM, PORTABLE_CONSOLE, COMPUTER, UNKNOWN", value.trim()),
+            ),
+        }
+    }
+
+    if query.generation.is_some_and(|generation| generation <= 0) {
+        errors.add("generation", "generation must be positive");
+    }
+    if query.family.is_some_and(|family| family <= 0) {
+        errors.add("family", "family must be a positive platform family id");
+    }
+    if query.page.is_some_and(|page| page < 0) {
+        errors.add("page", "page must not be negative");
+    }
+    if let Some(size) = query.size {
+        if !(1..=MAX_PLATFORM_PAGE_SIZE).contains(&size) {
+            errors.add("size", format!("size must be between 1 and {}", MAX_PLATFORM_PAGE_SIZE));
+        }
+    }
+
+    errors.into_result(PlatformFilter {
+        platform_types,
+        generation: query.generation,
+        family_id: query.family,
+    })
+}
+
+/// Validates the `GET /releases` query. Without dates the window starts on `today` and
+/// spans the next 90 days.
+pub fn to_release_filter(query: &ReleaseQueryDTO, today: NaiveDate) -> Result<ReleaseFilter, AppError> {
+    let mut errors = FieldErrors::default();
+
+    let mut parse_date = |field: &str, value: Option<&str>| -> Option<NaiveDate> {
+        value.and_then(|value| match NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d") {
+            Ok(date) => Some(date),
+            Err(_) => {
+                errors.add(field, format!("{} must be a date in YYYY-MM-DD format", field));
+                None
+            }
+        })
+    };
+    let from_param = parse_date("from", query.from.as_deref());
+    let to_param = parse_date("to", query.to.as_deref());
+
+    let from = from_param.unwrap_or(today);
+    let to = to_param.unwrap_or(from + Duration::days(DEFAULT_RELEASE_WINDOW_DAYS));
+    if to < from {
+        errors.add("to", "to must not be before from");
+    } else if (to - from).num_days() > MAX_RELEASE_WINDOW_DAYS {
+        errors.add("to", format!("the date range must not exceed {} days", MAX_RELEASE_WINDOW_DAYS));
+    }
+
+    let mut platform_ids = Vec::new();
+    for value in query.platform.iter().flat_map(|platforms| platforms.split(',')) {
+        match value.trim().parse::<i64>() {
+            Ok(id) if id > 0 => platform_ids.push(id),
+            _ => errors.add("platform", format!("'{}' is not a valid platform id", value.trim())),
+        }
+    }
+    if platform_ids.len() > MAX_FILTER_VALUES {
+        errors.add("platform", format!("at most {} platforms are allowed", MAX_FILTER_VALUES));
+    }
+
+    let region = query.region.as_deref().and_then(|value| {
+        let region = match value.trim() {
+            "EUROPE" => Some(ReleaseRegion::Europe),
+            "NORTH_AMERICA" => Some(ReleaseRegion::NorthAmerica),
+            "AUSTRALIA" => Some(ReleaseRegion::Australia),
+            "NEW_ZEALAND" => Some(ReleaseRegion::NewZealand),
+            "JAPAN" => Some(ReleaseRegion::Japan),
+            "CHINA" => Some(ReleaseRegion::China),
+            "ASIA" => Some(ReleaseRegion::Asia),
+            "WORLDWIDE" => Some(ReleaseRegion::Worldwide),
+            "KOREA" => Some(ReleaseRegion::Korea),
+            "BRAZIL" => Some(ReleaseRegion::Brazil),
+            _ => None,
+        };
+        if region.is_none() {
+            errors.add(
+                "region",
+                format!("Unknown region '{}'; expected one of: EUROPE, NORTH_AMERICA, AUSTRALIA, NEW_ZEALAND, JAPAN, CHINA, ASIA, WORLDWIDE, KOREA, BRAZIL", value.trim()),
+            );
+        }
+        region
+    });
+
+    if query.page.is_some_and(|page| page < 0) {
+        errors.add("page", "page must not be negative");
+    }
+    if let Some(size) = query.size {
+        if !(1..=MAX_RELEASE_PAGE_SIZE).contains(&size) {
+            errors.add("size", format!("size must be between 1 and {}", MAX_RELEASE_PAGE_SIZE));
+        }
+    }
+
+    errors.into_result(ReleaseFilter { from, to, platform_ids, region })
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use videogame_library_backend_rust::application::ports::output::game_catalog_repository::GameCatalogRepository;
+use videogame_library_backend_rust::domain::catalog::SyncStatus;
+use videogame_library_backend_rust::domain::error::DomainError;
+use videogame_library_backend_rust::infrastructure::persistence::game_catalog_repository::SqliteGameCatalogRepository;
+
+#[tokio::test]
+async fn a_running_sync_blocks_others_until_it_stalls_and_then_loses_its_run() {
+    let repository = SqliteGameCatalogRepository::new(common::migrated_pool().await);
+    let repository = &repository;
+    let start = |run_id| async move {
+        let mut progress = repository.load_progress().await.unwrap();
+        progress.status = SyncStatus::Running;
+        progress.run_id = Some(run_id);
+        progress
+    };
+
+    let first = start(Uuid::new_v4()).await;
+    assert!(repository.try_start_run(&first, Utc::now() - Duration::minutes(10)).await.unwrap());
+
+    let second = start(Uuid::new_v4()).await;
+    assert!(!repository.try_start_run(&second, Utc::now() - Duration::minutes(10)).await.unwrap());
+
+    // Once the first run counts as stalled, the second takes over and the first can't write.
+    assert!(repository.try_start_run(&second, Utc::now() + Duration::minutes(1)).await.unwrap());
+    assert!(matches!(repository.save_progress(&first).await, Err(DomainError::Conflict(_))));
+    repository.save_progress(&second).await.unwrap();
+    assert_eq!(repository.load_progress().await.unwrap().run_id, second.run_id);
+}
@@ -0,0 +1,168 @@
+//! Test harness: runs the whole application on a random local port against an in-memory
+//! SQLite database and a wiremock server standing in for both Twitch and IGDB.
+#![allow(dead_code)]
+
+use std::time::Duration;
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+use videogame_library_backend_rust::app::{self, AppConfig};
+
+pub const TOKEN_PATH: &str = "/oauth2/token";
+
+pub struct TestApp {
+    pub address: String,
+    pub http: reqwest::Client,
+    pub igdb: MockServer,
+}
+
+pub struct LoggedUser {
+    pub user_id: String,
+    pub token: String,
+}
+
+impl TestApp {
+    /// Starts the application with a Twitch token endpoint that always succeeds.
+    pub async fn spawn() -> Self {
+        let igdb = MockServer::start().await;
+        mount_token(&igdb, "test-token").await;
+        Self::spawn_with(igdb, |_| {}).await
+    }
+
+    /// Starts the application against an already configured stand-in server.
+    pub async fn spawn_with(igdb: MockServer, configure: impl FnOnce(&mut AppConfig)) -> Self {
+        let pool = migrated_pool().await;
+
+        let mut config = AppConfig::new(
+            "test-client".to_string(),
+            "test-secret".to_string(),
+            igdb.uri(),
+            format!("{}{}", igdb.uri(), TOKEN_PATH),
+        );
+        config.igdb_retry_policy.max_retries = 0;
+        config.igdb_retry_policy.base_delay = Duration::from_millis(1);
+        configure(&mut config);
+
+        let router = app::build_app(pool, &config).await.expect("Failed to build the app");
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.expect("Failed to bind");
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, router).await.expect("Server failed");
+        });
+
+        Self { address, http: reqwest::Client::new(), igdb }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.address, path)
+    }
+
+    pub async fn get(&self, path: &str, token: Option<&str>) -> reqwest::Response {
+        let mut request = self.http.get(self.url(path));
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        request.send().await.expect("Request failed")
+    }
+
+    pub async fn send_json(&self, method: reqwest::Method, path: &str, token: Option<&str>, body: &Value) -> reqwest::Response {
+        let mut request = self.http.request(method, self.url(path)).json(body);
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        request.send().await.expect("Request failed")
+    }
+
+    pub async fn register_and_login(&self, username: &str) -> LoggedUser {
+        let email = format!("{}@example.com", username);
+        let body = json!({ "username": username, "email": email, "password": "s3cret!" });
+        let response = self.send_json(reqwest::Method::POST, "/users/register", None, &body).await;
+        assert_eq!(response.status(), 200, "register failed");
+
+        let body = json!({ "email": email, "password": "s3cret!" });
+        let response = self.send_json(reqwest::Method::POST, "/users/login", None, &body).await;
+        assert_eq!(response.status(), 200, "login failed");
+        let login: Value = response.json().await.unwrap();
+
+        LoggedUser {
+            user_id: login["userId"].as_str().unwrap().to_string(),
+            token: login["token"].as_str().unwrap().to_string(),
+        }
+    }
+}
+
+/// A migrated in-memory database.
+pub async fn migrated_pool() -> SqlitePool {
+    // A single connection that never expires keeps the in-memory database alive.
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to open in-memory SQLite");
+    sqlx::migrate!("./migrations").run(&pool).await.expect("Failed to run migrations");
+    pool
+}
+
+pub async fn mount_token(server: &MockServer, access_token: &str) {
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(token_response(access_token))
+        .mount(server)
+        .await;
+}
+
+pub fn token_response(access_token: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "access_token": access_token,
+        "expires_in": 3600,
+        "token_type": "bearer",
+    }))
+}
+
+/// A game as IGDB returns it for the fields the provider asks for.
+pub fn igdb_game(id: i64, name: &str) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "rating": 85.0,
+        "first_release_date": 1262304000,
+        "cover": { "id": 100 + id, "image_id": format!("co{}", id), "width": 264, "height": 374 },
+        "platforms": [{ "id": 6, "name": "PC" }],
+        "genres": [{ "id": 5, "name": "Shooter" }],
+    })
+}
+
+/// Answers `games` queries with the known games whose ids appear in the `where id` clause.
+pub struct GamesById(pub Vec<Value>);
+
+impl Respond for GamesById {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let body = String::from_utf8_lossy(&request.body);
+        let ids: Vec<i64> = body
+            .split("where id = ")
+            .nth(1)
+            .map(|rest| {
+                rest.split(';').next().unwrap_or_default()
+                    .trim_matches(|c| c == '(' || c == ')' || c == ' ')
+                    .split(',')
+                    .filter_map(|id| id.trim().parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let games: Vec<&Value> = self.0.iter().filter(|game| ids.contains(&game["id"].as_i64().unwrap())).collect();
+        ResponseTemplate::new(200).set_body_json(games)
+    }
+}
+
+pub async fn mount_games(server: &MockServer, games: Vec<Value>) {
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(GamesById(games))
+        .mount(server)
+        .await;
+}
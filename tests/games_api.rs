@@ -0,0 +1,446 @@
+mod common;
+
+use common::{igdb_game, mount_games, token_response, TestApp, TOKEN_PATH};
+use reqwest::Method;
+use serde_json::{json, Value};
+use std::time::Duration;
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn batch_returns_found_games_in_request_order_and_missing_ids() {
+    let app = TestApp::spawn().await;
+    mount_games(&app.igdb, vec![igdb_game(1, "Doom"), igdb_game(2, "Quake")]).await;
+    // Lists don't show time-to-beat, so batches don't pay for the extra request.
+    Mock::given(method("POST"))
+        .and(path("/game_time_to_beats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(0)
+        .mount(&app.igdb)
+        .await;
+
+    let response = app.send_json(Method::POST, "/games/batch", None, &json!([2, 3, 1])).await;
+    assert_eq!(response.status(), 200);
+    let batch: Value = response.json().await.unwrap();
+
+    let names: Vec<&str> = batch["games"].as_array().unwrap().iter().map(|g| g["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["Quake", "Doom"]);
+    assert_eq!(batch["missingIds"], json!([3]));
+}
+
+#[tokio::test]
+async fn filter_sends_the_filter_to_igdb_and_pages_the_result() {
+    let app = TestApp::spawn().await;
+    Mock::given(method("POST"))
+        .and(path("/multiquery"))
+        .and(body_string_contains("platforms = (6)"))
+        .and(body_string_contains("games/count"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "name": "games", "result": [igdb_game(7, "Deus Ex"), igdb_game(8, "Thief")] },
+            { "name": "count", "count": 12 },
+        ])))
+        .expect(1)
+        .mount(&app.igdb)
+        .await;
+
+    let body = json!({ "platformIds": [6], "limit": 2, "offset": 4 });
+    let response = app.send_json(Method::POST, "/games/filter", None, &body).await;
+    assert_eq!(response.status(), 200);
+    let page: Value = response.json().await.unwrap();
+
+    assert_eq!(page["content"].as_array().unwrap().len(), 2);
+    assert_eq!(page["totalElements"], 12);
+    assert_eq!(page["number"], 2);
+    assert_eq!(page["totalPages"], 6);
+}
+
+#[tokio::test]
+async fn igdb_errors_surface_as_bad_gateway() {
+    let app = TestApp::spawn().await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+        .mount(&app.igdb)
+        .await;
+
+    let response = app.get("/games/42", None).await;
+    assert_eq!(response.status(), 502);
+    let error: Value = response.json().await.unwrap();
+    assert_eq!(error["error"], "Upstream service error");
+}
+
+#[tokio::test]
+async fn transient_igdb_errors_are_retried() {
+    let igdb = MockServer::start().await;
+    common::mount_token(&igdb, "test-token").await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&igdb)
+        .await;
+    mount_games(&igdb, vec![igdb_game(42, "Half-Life 2")]).await;
+    let app = TestApp::spawn_with(igdb, |config| config.igdb_retry_policy.max_retries = 2).await;
+
+    let response = app.get("/games/42", None).await;
+    assert_eq!(response.status(), 200);
+    let game: Value = response.json().await.unwrap();
+    assert_eq!(game["name"], "Half-Life 2");
+}
+
+#[tokio::test]
+async fn twitch_token_failure_surfaces_as_bad_gateway() {
+    let igdb = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&igdb)
+        .await;
+    mount_games(&igdb, vec![igdb_game(42, "Half-Life 2")]).await;
+    let app = TestApp::spawn_with(igdb, |_| {}).await;
+
+    assert_eq!(app.get("/games/42", None).await.status(), 502);
+}
+
+#[tokio::test]
+async fn twitch_tokens_without_a_lifetime_count_as_failed_refreshes() {
+    let igdb = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "already-expired",
+            "expires_in": 0,
+            "token_type": "bearer",
+        })))
+        .mount(&igdb)
+        .await;
+    mount_games(&igdb, vec![igdb_game(42, "Half-Life 2")]).await;
+    let app = TestApp::spawn_with(igdb, |_| {}).await;
+
+    assert_eq!(app.get("/games/42", None).await.status(), 502);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let health: Value = app.get("/health", None).await.json().await.unwrap();
+    let token_requests = app.igdb.received_requests().await.unwrap()
+        .iter()
+        .filter(|request| request.url.path() == TOKEN_PATH)
+        .count();
+    assert!(token_requests > 0);
+    assert_eq!(health["twitchToken"]["failures"], token_requests);
+    assert_eq!(health["twitchToken"]["refreshes"], 0);
+    assert!(health["twitchToken"]["expiresInSeconds"].is_null());
+}
+
+#[tokio::test]
+async fn expired_twitch_token_is_refreshed_once() {
+    let igdb = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(token_response("stale-token"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&igdb)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(token_response("fresh-token"))
+        .expect(1)
+        .mount(&igdb)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .and(header("Authorization", "Bearer stale-token"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&igdb)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .and(header("Authorization", "Bearer fresh-token"))
+        .respond_with(common::GamesById(vec![igdb_game(42, "Half-Life 2")]))
+        .mount(&igdb)
+        .await;
+    let app = TestApp::spawn_with(igdb, |_| {}).await;
+
+    let response = app.get("/games/42", None).await;
+    assert_eq!(response.status(), 200);
+
+    // Served from the game cache: no further token or IGDB calls.
+    assert_eq!(app.get("/games/42", None).await.status(), 200);
+}
+
+#[tokio::test]
+async fn concurrent_requests_share_one_token_refresh() {
+    let igdb = MockServer::start().await;
+    // Fails the fetch made while loading platforms at startup, so no token is cached.
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&igdb)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(token_response("test-token").set_delay(Duration::from_millis(200)))
+        .expect(1)
+        .mount(&igdb)
+        .await;
+    mount_games(&igdb, (1..=5).map(|id| igdb_game(id, "Doom")).collect()).await;
+    let app = TestApp::spawn_with(igdb, |_| {}).await;
+
+    let paths: Vec<String> = (1..=5).map(|id| format!("/games/{}", id)).collect();
+    let responses = futures::future::join_all(paths.iter().map(|path| app.get(path, None))).await;
+    assert!(responses.iter().all(|response| response.status() == 200));
+
+    let health: Value = app.get("/health", None).await.json().await.unwrap();
+    assert_eq!(health["twitchToken"]["refreshes"], 1);
+    assert_eq!(health["twitchToken"]["failures"], 1);
+    assert!(health["twitchToken"]["expiresInSeconds"].as_u64().unwrap() > 3000);
+}
+
+#[tokio::test]
+async fn concurrent_identical_lookups_share_one_igdb_call() {
+    let igdb = MockServer::start().await;
+    common::mount_token(&igdb, "test-token").await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([igdb_game(42, "Half-Life 2")])).set_delay(Duration::from_millis(200)))
+        .expect(1)
+        .mount(&igdb)
+        .await;
+    let app = TestApp::spawn_with(igdb, |_| {}).await;
+
+    let responses = futures::future::join_all((0..5).map(|_| app.get("/games/42", None))).await;
+    assert!(responses.iter().all(|response| response.status() == 200));
+
+    let health: Value = app.get("/health", None).await.json().await.unwrap();
+    assert!(health["igdbRequests"]["coalescedCalls"].as_u64().unwrap() >= 4);
+}
+
+#[tokio::test]
+async fn outbound_requests_use_the_configured_user_agent() {
+    let igdb = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .and(header("User-Agent", "library-tests/1.0"))
+        .respond_with(token_response("test-token"))
+        .mount(&igdb)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .and(header("User-Agent", "library-tests/1.0"))
+        .respond_with(common::GamesById(vec![igdb_game(42, "Half-Life 2")]))
+        .expect(1)
+        .mount(&igdb)
+        .await;
+    let app = TestApp::spawn_with(igdb, |config| config.http.user_agent = "library-tests/1.0".to_string()).await;
+
+    assert_eq!(app.get("/games/42", None).await.status(), 200);
+}
+
+#[tokio::test]
+async fn igdb_calls_give_up_after_the_overall_timeout() {
+    let igdb = MockServer::start().await;
+    common::mount_token(&igdb, "test-token").await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])).set_delay(Duration::from_secs(5)))
+        .mount(&igdb)
+        .await;
+    let app = TestApp::spawn_with(igdb, |config| config.http.overall_timeout = Duration::from_millis(300)).await;
+
+    let started = std::time::Instant::now();
+    assert_eq!(app.get("/games/42", None).await.status(), 502);
+    assert!(started.elapsed() < Duration::from_secs(3));
+}
+
+#[tokio::test]
+async fn time_to_beat_is_fetched_once_for_details_and_kept_when_lists_recache_the_game() {
+    let app = TestApp::spawn().await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .and(body_string_contains("search \"Celeste\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([igdb_game(2, "Celeste")])))
+        .mount(&app.igdb)
+        .await;
+    mount_games(&app.igdb, vec![igdb_game(2, "Celeste")]).await;
+    Mock::given(method("POST"))
+        .and(path("/game_time_to_beats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{ "id": 902, "game_id": 2, "hastily": 28800 }])))
+        .expect(1)
+        .mount(&app.igdb)
+        .await;
+
+    let details: Value = app.get("/games/2", None).await.json().await.unwrap();
+    assert_eq!(details["timeToBeat"]["hastilyHours"], 8.0);
+
+    let results: Vec<Value> = app.get("/games/search?name=Celeste", None).await.json().await.unwrap();
+    assert_eq!(results.len(), 1);
+
+    let details: Value = app.get("/games/2", None).await.json().await.unwrap();
+    assert_eq!(details["timeToBeat"]["hastilyHours"], 8.0);
+}
+
+#[tokio::test]
+async fn games_without_time_to_beat_data_are_looked_up_once() {
+    let app = TestApp::spawn().await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .and(body_string_contains("search \"Celeste\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([igdb_game(2, "Celeste")])))
+        .mount(&app.igdb)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/game_time_to_beats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&app.igdb)
+        .await;
+
+    // Cached by the search, without time-to-beat; every detail lookup is then a cache hit.
+    let results: Vec<Value> = app.get("/games/search?name=Celeste", None).await.json().await.unwrap();
+    assert_eq!(results.len(), 1);
+    for _ in 0..3 {
+        let details: Value = app.get("/games/2", None).await.json().await.unwrap();
+        assert!(details["timeToBeat"].is_null());
+    }
+}
+
+#[tokio::test]
+async fn token_rejected_twice_is_reported_as_upstream_error() {
+    let app = TestApp::spawn().await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(2)
+        .mount(&app.igdb)
+        .await;
+
+    assert_eq!(app.get("/games/42", None).await.status(), 502);
+}
+
+#[tokio::test]
+async fn rejected_igdb_queries_do_not_fall_back_to_stale_games() {
+    let igdb = MockServer::start().await;
+    common::mount_token(&igdb, "test-token").await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(common::GamesById(vec![igdb_game(42, "Half-Life 2")]))
+        .up_to_n_times(1)
+        .mount(&igdb)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Syntax error"))
+        .mount(&igdb)
+        .await;
+    let app = TestApp::spawn_with(igdb, |config| config.game_cache_ttl = Duration::ZERO).await;
+
+    assert_eq!(app.get("/games/42", None).await.status(), 200);
+
+    let response = app.get("/games/42", None).await;
+    assert_eq!(response.status(), 500);
+    assert!(response.headers().get("x-stale-data").is_none());
+    let health: Value = app.get("/health", None).await.json().await.unwrap();
+    assert_eq!(health["gameCache"]["staleHits"], 0);
+    assert_eq!(health["igdbCircuitBreaker"]["state"], "CLOSED");
+}
+
+#[tokio::test]
+async fn similar_games_skip_the_callers_library() {
+    let app = TestApp::spawn().await;
+    let mut zelda = igdb_game(1, "Zelda");
+    zelda["similar_games"] = json!([3, 2, 4]);
+    mount_games(&app.igdb, vec![zelda, igdb_game(2, "Okami"), igdb_game(3, "Darksiders"), igdb_game(4, "Hollow Knight")]).await;
+
+    let similar: Vec<Value> = app.get("/games/1/similar", None).await.json().await.unwrap();
+    let names: Vec<&str> = similar.iter().map(|g| g["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["Darksiders", "Okami", "Hollow Knight"]);
+
+    let user = app.register_and_login("frank").await;
+    let path = format!("/users/{}/games/2", user.user_id);
+    let response = app.send_json(Method::PUT, &path, Some(&user.token), &json!({ "status": "PLAYING" })).await;
+    assert_eq!(response.status(), 200);
+
+    let similar: Vec<Value> = app.get("/games/1/similar", Some(&user.token)).await.json().await.unwrap();
+    let names: Vec<&str> = similar.iter().map(|g| g["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["Darksiders", "Hollow Knight"]);
+
+    assert_eq!(app.get("/games/1/similar", Some("not-a-jwt")).await.status(), 401);
+    assert_eq!(app.get("/games/99/similar", None).await.status(), 404);
+}
+
+#[tokio::test]
+async fn autocomplete_prefers_the_local_index_and_falls_back_to_igdb_once() {
+    let app = TestApp::spawn().await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .and(body_string_contains("search \"Por\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([igdb_game(10, "Portal"), igdb_game(11, "Portal 2")])))
+        .expect(1)
+        .mount(&app.igdb)
+        .await;
+    mount_games(&app.igdb, vec![igdb_game(20, "Portal Knights")]).await;
+
+    // Games seen through any other endpoint are indexed.
+    let response = app.send_json(Method::POST, "/games/batch", None, &json!([20])).await;
+    assert_eq!(response.status(), 200);
+
+    let suggestions: Vec<Value> = app.get("/games/autocomplete?q=Por&limit=3", None).await.json().await.unwrap();
+    let names: Vec<&str> = suggestions.iter().map(|s| s["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["Portal Knights", "Portal", "Portal 2"]);
+    assert_eq!(suggestions[1]["releaseYear"], 2010);
+    assert_eq!(suggestions[1]["coverThumbUrl"], "https://images.igdb.com/igdb/image/upload/t_thumb/co10.jpg");
+
+    // Enough local matches now: no second IGDB search.
+    let suggestions: Vec<Value> = app.get("/games/autocomplete?q=por&limit=3", None).await.json().await.unwrap();
+    let names: Vec<&str> = suggestions.iter().map(|s| s["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["Portal", "Portal 2", "Portal Knights"]);
+
+    assert_eq!(app.get("/games/autocomplete?q=%20", None).await.status(), 400);
+    assert_eq!(app.get("/games/autocomplete?q=por&limit=50", None).await.status(), 400);
+}
+
+#[tokio::test]
+async fn open_circuit_serves_the_last_known_game_marked_as_stale() {
+    let igdb = MockServer::start().await;
+    common::mount_token(&igdb, "test-token").await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(common::GamesById(vec![igdb_game(42, "Half-Life 2")]))
+        .up_to_n_times(1)
+        .mount(&igdb)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&igdb)
+        .await;
+    let app = TestApp::spawn_with(igdb, |config| {
+        config.game_cache_ttl = Duration::ZERO;
+        config.igdb_circuit_breaker.failure_threshold = 1;
+        config.igdb_circuit_breaker.open_duration = Duration::from_secs(60);
+    }).await;
+
+    let response = app.get("/games/42", None).await;
+    assert_eq!(response.status(), 200);
+    assert!(response.headers().get("x-stale-data").is_none());
+
+    // The cache entry has expired and IGDB fails, which opens the circuit.
+    let response = app.get("/games/42", None).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["x-stale-data"], "true");
+    let game: Value = response.json().await.unwrap();
+    assert_eq!(game["name"], "Half-Life 2");
+
+    let health: Value = app.get("/health", None).await.json().await.unwrap();
+    assert_eq!(health["igdbCircuitBreaker"]["state"], "OPEN");
+    assert_eq!(health["gameCache"]["staleHits"], 1);
+
+    // While open, IGDB is not called at all.
+    let response = app.send_json(Method::POST, "/games/batch", None, &json!([42])).await;
+    assert_eq!(response.headers()["x-stale-data"], "true");
+    assert_eq!(app.get("/games/7", None).await.status(), 502);
+    let health: Value = app.get("/health", None).await.json().await.unwrap();
+    assert_eq!(health["igdbCircuitBreaker"]["rejectedCalls"], 2);
+}
@@ -0,0 +1,178 @@
+mod common;
+
+use common::{igdb_game, mount_games, TestApp};
+use jsonwebtoken::{encode, EncodingKey, Header};
+use reqwest::Method;
+use serde_json::{json, Value};
+use videogame_library_backend_rust::domain::auth::Claims;
+
+#[tokio::test]
+async fn register_rejects_duplicate_email_and_login_checks_password() {
+    let app = TestApp::spawn().await;
+    app.register_and_login("alice").await;
+
+    let duplicate = json!({ "username": "alice2", "email": "alice@example.com", "password": "other" });
+    let response = app.send_json(Method::POST, "/users/register", None, &duplicate).await;
+    assert_eq!(response.status(), 409);
+
+    let wrong_password = json!({ "email": "alice@example.com", "password": "wrong" });
+    let response = app.send_json(Method::POST, "/users/login", None, &wrong_password).await;
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn library_upsert_update_and_removal() {
+    let app = TestApp::spawn().await;
+    mount_games(&app.igdb, vec![igdb_game(10, "Portal"), igdb_game(20, "Half-Life")]).await;
+    let user = app.register_and_login("bob").await;
+    let game_path = format!("/users/{}/games/10", user.user_id);
+
+    let response = app.send_json(Method::PUT, &game_path, Some(&user.token), &json!({ "status": "PLAYING" })).await;
+    assert_eq!(response.status(), 200);
+    let entry: Value = response.json().await.unwrap();
+    assert_eq!(entry["gameId"], 10);
+    assert_eq!(entry["status"], "PLAYING");
+    assert_eq!(entry["isFavorite"], false);
+
+    let response = app.send_json(Method::PUT, &game_path, Some(&user.token), &json!({ "status": "COMPLETED" })).await;
+    assert_eq!(response.status(), 200);
+
+    let response = app.get(&game_path, Some(&user.token)).await;
+    let entry: Value = response.json().await.unwrap();
+    assert_eq!(entry["status"], "COMPLETED");
+
+    let library: Vec<Value> = app.get(&format!("/users/{}/games", user.user_id), Some(&user.token)).await.json().await.unwrap();
+    assert_eq!(library.len(), 1);
+
+    let response = app.http.delete(app.url(&game_path)).bearer_auth(&user.token).send().await.unwrap();
+    assert_eq!(response.status(), 204);
+    assert_eq!(app.get(&game_path, Some(&user.token)).await.status(), 404);
+}
+
+#[tokio::test]
+async fn library_upsert_of_unknown_game_is_not_found() {
+    let app = TestApp::spawn().await;
+    mount_games(&app.igdb, vec![]).await;
+    let user = app.register_and_login("carol").await;
+
+    let path = format!("/users/{}/games/999", user.user_id);
+    let response = app.send_json(Method::PUT, &path, Some(&user.token), &json!({ "status": "PLAYING" })).await;
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn malformed_json_bodies_are_reported_as_field_errors() {
+    let app = TestApp::spawn().await;
+    let user = app.register_and_login("dave").await;
+
+    let path = format!("/users/{}/games/10", user.user_id);
+    let response = app.send_json(Method::PUT, &path, Some(&user.token), &json!({ "status": "NOT_A_STATUS" })).await;
+    assert_eq!(response.status(), 400);
+    let error: Value = response.json().await.unwrap();
+    assert_eq!(error["error"], "Validation failed");
+    assert_eq!(error["fieldErrors"][0]["field"], "status");
+    assert!(error["fieldErrors"][0]["message"].as_str().unwrap().contains("NOT_A_STATUS"));
+
+    let response = app.send_json(Method::POST, "/games/filter", None, &json!({ "platformIds": ["six"] })).await;
+    assert_eq!(response.status(), 400);
+    let error: Value = response.json().await.unwrap();
+    assert_eq!(error["fieldErrors"][0]["field"], "platformIds[0]");
+}
+
+#[tokio::test]
+async fn favorites_are_paged() {
+    let app = TestApp::spawn().await;
+    mount_games(&app.igdb, (1..=5).map(|id| igdb_game(id, &format!("Game {}", id))).collect()).await;
+    let user = app.register_and_login("dave").await;
+
+    for id in 1..=5 {
+        let path = format!("/users/{}/games/{}/favorite", user.user_id, id);
+        let response = app.send_json(Method::POST, &path, Some(&user.token), &json!({})).await;
+        assert_eq!(response.status(), 200);
+    }
+
+    let path = format!("/users/{}/favorites?page=1&size=2", user.user_id);
+    let page: Value = app.get(&path, Some(&user.token)).await.json().await.unwrap();
+    assert_eq!(page["content"].as_array().unwrap().len(), 2);
+    assert_eq!(page["totalElements"], 5);
+    assert_eq!(page["totalPages"], 3);
+    assert_eq!(page["number"], 1);
+    assert_eq!(page["first"], false);
+    assert_eq!(page["last"], false);
+
+    let path = format!("/users/{}/favorites?page=2&size=2", user.user_id);
+    let page: Value = app.get(&path, Some(&user.token)).await.json().await.unwrap();
+    assert_eq!(page["content"].as_array().unwrap().len(), 1);
+    assert_eq!(page["last"], true);
+}
+
+#[tokio::test]
+async fn library_requires_a_valid_token_for_the_same_user() {
+    let app = TestApp::spawn().await;
+    let alice = app.register_and_login("erin").await;
+    let mallory = app.register_and_login("mallory").await;
+    let library_path = format!("/users/{}/games", alice.user_id);
+
+    assert_eq!(app.get(&library_path, None).await.status(), 400);
+    assert_eq!(app.get(&library_path, Some("not-a-jwt")).await.status(), 401);
+    assert_eq!(app.get(&library_path, Some(&mallory.token)).await.status(), 401);
+
+    let expired = Claims {
+        sub: "erin@example.com".to_string(),
+        user_id: alice.user_id.clone(),
+        exp: (chrono::Utc::now().timestamp() - 3600) as usize,
+    };
+    let expired_token = encode(&Header::default(), &expired, &EncodingKey::from_secret(b"secret")).unwrap();
+    assert_eq!(app.get(&library_path, Some(&expired_token)).await.status(), 401);
+
+    assert_eq!(app.get(&library_path, Some(&alice.token)).await.status(), 200);
+}
+
+#[tokio::test]
+async fn backlog_estimate_sums_want_to_play_and_playing_games() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    let app = TestApp::spawn().await;
+    mount_games(&app.igdb, vec![
+        igdb_game(1, "Hades"),
+        igdb_game(2, "Celeste"),
+        igdb_game(3, "Portal"),
+        igdb_game(4, "Outer Wilds"),
+    ]).await;
+    Mock::given(method("POST"))
+        .and(path("/game_time_to_beats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "id": 901, "game_id": 1, "normally": 79200 },
+            { "id": 902, "game_id": 2, "hastily": 28800, "normally": 43200, "completely": 129600 },
+            { "id": 903, "game_id": 3, "hastily": 10800, "normally": 14400, "completely": 21600 },
+        ])))
+        .mount(&app.igdb)
+        .await;
+    let user = app.register_and_login("grace").await;
+
+    for (game_id, status) in [(1, "WANT_TO_PLAY"), (2, "PLAYING"), (3, "COMPLETED"), (4, "WANT_TO_PLAY")] {
+        let path = format!("/users/{}/games/{}", user.user_id, game_id);
+        let response = app.send_json(Method::PUT, &path, Some(&user.token), &json!({ "status": status })).await;
+        assert_eq!(response.status(), 200);
+    }
+
+    // Statuses survive the round trip through the database.
+    let entry: Value = app.get(&format!("/users/{}/games/1", user.user_id), Some(&user.token)).await.json().await.unwrap();
+    assert_eq!(entry["status"], "WANT_TO_PLAY");
+
+    let details: Value = app.get("/games/2", None).await.json().await.unwrap();
+    assert_eq!(details["timeToBeat"]["hastilyHours"], 8.0);
+
+    let path = format!("/users/{}/backlog/estimate", user.user_id);
+    let estimate: Value = app.get(&path, Some(&user.token)).await.json().await.unwrap();
+    assert_eq!(estimate["gameCount"], 3);
+    // Hades only reports `normally`, which stands in for its other paces.
+    assert_eq!(estimate["hastilyHours"], 30.0);
+    assert_eq!(estimate["normallyHours"], 34.0);
+    assert_eq!(estimate["completelyHours"], 58.0);
+    assert_eq!(estimate["gamesWithoutEstimate"], json!([4]));
+
+    let other = app.register_and_login("heidi").await;
+    assert_eq!(app.get(&path, Some(&other.token)).await.status(), 401);
+}
@@ -0,0 +1,159 @@
+mod common;
+
+use common::{igdb_game, mount_games, mount_token, GamesById, TestApp};
+use reqwest::Method;
+use serde_json::{json, Value};
+use std::time::Duration;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+// The metadata API stand-in lives under `/metadata` on the same mock server as IGDB.
+async fn spawn_with_metadata() -> TestApp {
+    let igdb = MockServer::start().await;
+    mount_token(&igdb, "test-token").await;
+    spawn_on(igdb, Duration::from_secs(3600)).await
+}
+
+async fn spawn_on(igdb: MockServer, game_cache_ttl: Duration) -> TestApp {
+    let metadata_url = format!("{}/metadata", igdb.uri());
+    TestApp::spawn_with(igdb, |config| {
+        config.game_metadata_url = Some(metadata_url);
+        config.game_cache_ttl = game_cache_ttl;
+    }).await
+}
+
+fn metadata_game(id: i64, name: &str) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "summary": format!("{} from the metadata API", name),
+        "release_date": "2017-03-03",
+        "rating": 9.7,
+        "platforms": ["Nintendo Switch"],
+        "genres": ["Adventure"],
+    })
+}
+
+#[tokio::test]
+async fn metadata_api_serves_games_and_library_writes_while_igdb_is_down() {
+    let app = spawn_with_metadata().await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&app.igdb)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/metadata/games/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(metadata_game(42, "Breath of the Wild")))
+        .mount(&app.igdb)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/metadata/games/43"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&app.igdb)
+        .await;
+
+    let response = app.get("/games/42", None).await;
+    assert_eq!(response.status(), 200);
+    let game: Value = response.json().await.unwrap();
+    assert_eq!(game["name"], "Breath of the Wild");
+    assert_eq!(game["releaseDate"], "2017-03-03");
+    assert_eq!(game["sources"], json!(["metadata"]));
+
+    let user = app.register_and_login("ivan").await;
+    let path = format!("/users/{}/games/42", user.user_id);
+    let response = app.send_json(Method::PUT, &path, Some(&user.token), &json!({ "status": "PLAYING" })).await;
+    assert_eq!(response.status(), 200);
+
+    // IGDB might know a game the metadata API doesn't, so this is not reported as 404.
+    assert_eq!(app.get("/games/43", None).await.status(), 502);
+}
+
+#[tokio::test]
+async fn metadata_api_fills_fields_igdb_leaves_empty() {
+    let app = spawn_with_metadata().await;
+    let mut game = igdb_game(7, "Outer Wilds");
+    game["genres"] = json!([]);
+    mount_games(&app.igdb, vec![game]).await;
+    Mock::given(method("GET"))
+        .and(path("/metadata/games/7"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(metadata_game(7, "Outer Wilds")))
+        .expect(1)
+        .mount(&app.igdb)
+        .await;
+
+    let game: Value = app.get("/games/7", None).await.json().await.unwrap();
+    assert_eq!(game["genres"], json!(["Adventure"]));
+    assert_eq!(game["summary"], "Outer Wilds from the metadata API");
+    // IGDB's own values win.
+    assert_eq!(game["platforms"], json!(["PC"]));
+    assert_eq!(game["sources"], json!(["igdb", "metadata"]));
+}
+
+#[tokio::test]
+async fn games_only_the_metadata_api_answered_are_not_cached() {
+    let app = spawn_with_metadata().await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&app.igdb)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/metadata/games/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(metadata_game(42, "Breath of the Wild")))
+        .expect(2)
+        .mount(&app.igdb)
+        .await;
+
+    for _ in 0..2 {
+        let game: Value = app.get("/games/42", None).await.json().await.unwrap();
+        assert_eq!(game["sources"], json!(["metadata"]));
+    }
+    let health: Value = app.get("/health", None).await.json().await.unwrap();
+    assert_eq!(health["gameCache"]["hits"], 0);
+}
+
+#[tokio::test]
+async fn expired_igdb_games_win_over_metadata_answers_while_igdb_is_down() {
+    let igdb = MockServer::start().await;
+    mount_token(&igdb, "test-token").await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(GamesById(vec![igdb_game(42, "The Legend of Zelda: Breath of the Wild")]))
+        .up_to_n_times(1)
+        .mount(&igdb)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/games"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&igdb)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/metadata/games/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(metadata_game(42, "Breath of the Wild")))
+        .mount(&igdb)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/metadata/games"))
+        .and(query_param("ids", "42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([metadata_game(42, "Breath of the Wild")])))
+        .mount(&igdb)
+        .await;
+    let app = spawn_on(igdb, Duration::ZERO).await;
+
+    let game: Value = app.get("/games/42", None).await.json().await.unwrap();
+    assert_eq!(game["name"], "The Legend of Zelda: Breath of the Wild");
+
+    // The cache entry has expired and IGDB fails; the metadata API answers, but the
+    // expired IGDB copy is served instead.
+    let response = app.get("/games/42", None).await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["x-stale-data"], "true");
+    let game: Value = response.json().await.unwrap();
+    assert_eq!(game["name"], "The Legend of Zelda: Breath of the Wild");
+
+    let response = app.send_json(Method::POST, "/games/batch", None, &json!([42])).await;
+    assert_eq!(response.headers()["x-stale-data"], "true");
+    let batch: Value = response.json().await.unwrap();
+    assert_eq!(batch["games"][0]["name"], "The Legend of Zelda: Breath of the Wild");
+}
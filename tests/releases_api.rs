@@ -0,0 +1,64 @@
+mod common;
+
+use common::TestApp;
+use serde_json::{json, Value};
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn releases_are_filtered_by_platform_region_and_dates_with_their_precision() {
+    let app = TestApp::spawn().await;
+    Mock::given(method("POST"))
+        .and(path("/multiquery"))
+        .and(body_string_contains("release_dates/count"))
+        .and(body_string_contains("platform = (48)"))
+        .and(body_string_contains("release_region = 1"))
+        // 2025-03-01 .. 2025-03-31 inclusive
+        .and(body_string_contains("date >= 1740787200"))
+        .and(body_string_contains("date < 1743465600"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "name": "releases", "result": [
+                { "id": 1, "date": 1741046400, "human": "Mar 04, 2025", "date_format": { "id": 1, "format": "YYYYMMMMDD" },
+                  "game": { "id": 10, "name": "Split Fiction" }, "platform": { "id": 48, "name": "PlayStation 4" },
+                  "release_region": { "id": 1, "region": "europe" } },
+                { "id": 2, "date": 1740787200, "human": "Mar 2025", "category": 1,
+                  "game": { "id": 11, "name": "Some Game" }, "platform": { "id": 48, "name": "PlayStation 4" },
+                  "release_region": { "id": 1, "region": "europe" } },
+                { "id": 3, "date": 1740787200, "human": "Q1 2025", "date_format": { "id": 4, "format": "YYYYQ1" },
+                  "game": { "id": 12, "name": "Another Game" } },
+            ]},
+            { "name": "count", "count": 3 },
+        ])))
+        .expect(1)
+        .mount(&app.igdb)
+        .await;
+
+    let response = app.get("/releases?from=2025-03-01&to=2025-03-31&platform=48&region=EUROPE", None).await;
+    assert_eq!(response.status(), 200);
+    let page: Value = response.json().await.unwrap();
+
+    assert_eq!(page["totalElements"], 3);
+    let releases = page["content"].as_array().unwrap();
+    assert_eq!(releases[0]["gameName"], "Split Fiction");
+    assert_eq!(releases[0]["platformName"], "PlayStation 4");
+    assert_eq!(releases[0]["region"], "europe");
+    assert_eq!(releases[0]["date"], "2025-03-04");
+    assert_eq!(releases[0]["precision"], "DAY");
+    assert_eq!(releases[1]["precision"], "MONTH");
+    assert_eq!(releases[2]["precision"], "QUARTER");
+    assert_eq!(releases[2]["platformId"], Value::Null);
+}
+
+#[tokio::test]
+async fn invalid_release_queries_are_rejected() {
+    let app = TestApp::spawn().await;
+
+    let response = app.get("/releases?from=2025-03-31&to=2025-03-01&region=MARS&platform=abc", None).await;
+    assert_eq!(response.status(), 400);
+    let error: Value = response.json().await.unwrap();
+    let fields: Vec<&str> = error["fieldErrors"].as_array().unwrap().iter().map(|e| e["field"].as_str().unwrap()).collect();
+    assert_eq!(fields, vec!["to", "platform", "region"]);
+
+    assert_eq!(app.get("/releases?from=2025-01-01&to=2026-06-01", None).await.status(), 400);
+    assert_eq!(app.get("/releases?from=03/01/2025", None).await.status(), 400);
+}